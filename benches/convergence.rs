@@ -0,0 +1,148 @@
+//! Mede o tempo até a convergência na cúbica padrão para cada combinação de seleção e
+//! reorganização, com semente fixa para que cada amostra repita a mesma execução:
+//!
+//! ```text
+//! cargo bench --bench convergence
+//! ```
+//!
+//! As combinações que não convergem param em [`MAX_GENERATIONS`], então o tempo delas mede
+//! o custo de um número fixo de gerações. Cada seleção também é medida sem reorganização e
+//! com a busca local padrão, para comparar com a versão sem ela, e com o genocídio e o
+//! tamanho variável de [`ADAPTIVE_SIZE`], para comparar com o tamanho fixo. Antes dessas
+//! duas comparações, imprime, para cada uma das [`SEEDS`], as gerações com e sem a busca
+//! local e as avaliações com o tamanho fixo e com o variável.
+
+use criterion::{criterion_group, criterion_main, BatchSize, Criterion};
+use rootfn::{
+    functions, AdaptiveSize, LocalSearch, Population, PopulationBuilder, Rearrangement, Selection,
+    SolveResult,
+};
+
+/// Limite de gerações de cada execução.
+const MAX_GENERATIONS: u64 = 5_000;
+
+/// Tamanho variável comparado com o tamanho fixo padrão de 100 indivíduos.
+const ADAPTIVE_SIZE: AdaptiveSize = AdaptiveSize {
+    min: 20,
+    max: 200,
+    step: 10,
+};
+
+/// Sementes das comparações impressas, as mesmas para as duas versões comparadas.
+const SEEDS: [u64; 5] = [1, 2, 3, 4, 5];
+
+fn builder(selection: Selection, rearrangement: Rearrangement) -> PopulationBuilder {
+    PopulationBuilder::new(functions::CUBIC.function)
+        .seed(42)
+        .selection(selection)
+        .rearrangement(rearrangement)
+        .max_generations(MAX_GENERATIONS)
+}
+
+fn population(selection: Selection, rearrangement: Rearrangement) -> Population {
+    builder(selection, rearrangement).build().unwrap()
+}
+
+/// Resultado de uma execução de `builder` para cada uma das [`SEEDS`].
+fn results(builder: impl Fn() -> PopulationBuilder) -> Vec<SolveResult> {
+    SEEDS
+        .iter()
+        .map(|&seed| builder().seed(seed).build().unwrap().run(false).unwrap())
+        .collect()
+}
+
+fn convergence(c: &mut Criterion) {
+    let mut group = c.benchmark_group("convergence");
+    group.sample_size(10);
+
+    for selection in [Selection::Elitism, Selection::default()] {
+        for rearrangement in [
+            Rearrangement::None,
+            Rearrangement::Genocide,
+            Rearrangement::RandomPredation,
+            Rearrangement::Restart,
+        ] {
+            let name = match rearrangement {
+                Rearrangement::None => selection.to_string(),
+                _ => format!("{}_{}", selection, rearrangement),
+            };
+
+            group.bench_function(name, |b| {
+                b.iter_batched(
+                    || population(selection, rearrangement),
+                    |mut pop| pop.run(false).unwrap(),
+                    BatchSize::LargeInput,
+                )
+            });
+        }
+
+        let generations = |local_search: Option<LocalSearch>| -> Vec<u64> {
+            results(|| {
+                let builder = builder(selection, Rearrangement::None);
+                match local_search {
+                    Some(local_search) => builder.local_search(local_search),
+                    None => builder,
+                }
+            })
+            .iter()
+            .map(|result| result.generations)
+            .collect()
+        };
+        println!(
+            "{}_local_search: generations {:?} without, {:?} with",
+            selection,
+            generations(None),
+            generations(Some(LocalSearch::default())),
+        );
+
+        group.bench_function(format!("{}_local_search", selection), |b| {
+            b.iter_batched(
+                || {
+                    builder(selection, Rearrangement::None)
+                        .local_search(LocalSearch::default())
+                        .build()
+                        .unwrap()
+                },
+                |mut pop| pop.run(false).unwrap(),
+                BatchSize::LargeInput,
+            )
+        });
+
+        let evaluations = |adaptive_size: Option<AdaptiveSize>| -> Vec<u64> {
+            results(|| {
+                let builder = builder(selection, Rearrangement::Genocide);
+                match adaptive_size {
+                    Some(adaptive_size) => builder.adaptive_size(adaptive_size),
+                    None => builder,
+                }
+            })
+            .iter()
+            .map(|result| result.evaluations)
+            .collect()
+        };
+        println!(
+            "{}_genocide_adaptive_size: evaluations {:?} fixed, {:?} adaptive",
+            selection,
+            evaluations(None),
+            evaluations(Some(ADAPTIVE_SIZE)),
+        );
+
+        group.bench_function(format!("{}_genocide_adaptive_size", selection), |b| {
+            b.iter_batched(
+                || {
+                    builder(selection, Rearrangement::Genocide)
+                        .adaptive_size(ADAPTIVE_SIZE)
+                        .build()
+                        .unwrap()
+                },
+                |mut pop| pop.run(false).unwrap(),
+                BatchSize::LargeInput,
+            )
+        });
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, convergence);
+criterion_main!(benches);
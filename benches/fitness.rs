@@ -0,0 +1,88 @@
+//! Compara a avaliação serial da *fitness* com a avaliação da população inteira, que é
+//! paralela com a *feature* `rayon`, e mede o custo de algumas gerações completas, dominado
+//! pelas avaliações que o cache de *fitness* evita, contra uma linha de base que reavalia
+//! todos os genomas a cada geração:
+//!
+//! ```text
+//! cargo bench --bench fitness
+//! cargo bench --bench fitness --features rayon
+//! ```
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use rootfn::{PopulationBuilder, Selection};
+
+/// Cúbica com um atraso artificial, simulando uma função cara de avaliar.
+fn slow_function(x: f64) -> f64 {
+    let mut y = (x - 478.0) * (x + 4567.0) * (x - 1240.0);
+    for _ in 0..10_000 {
+        y = black_box(y);
+    }
+    y
+}
+
+fn fitness_evaluation(c: &mut Criterion) {
+    let pop = PopulationBuilder::new(slow_function)
+        .seed(42)
+        .selection(Selection::Elitism)
+        .build()
+        .unwrap();
+
+    let mut group = c.benchmark_group("fitness_evaluation");
+
+    group.bench_function("serial", |b| {
+        b.iter(|| {
+            let mut best = 0;
+            let mut best_fitness = f64::INFINITY;
+            for i in 0..100 {
+                let fitness = pop.fitness(pop.value(i).unwrap());
+                if fitness < best_fitness {
+                    best_fitness = fitness;
+                    best = i;
+                }
+            }
+            best
+        })
+    });
+
+    group.bench_function("best_index", |b| b.iter(|| pop.best_index().unwrap()));
+
+    group.finish();
+}
+
+fn generations(c: &mut Criterion) {
+    let mut group = c.benchmark_group("generations");
+
+    for selection in [Selection::Elitism, Selection::default()] {
+        let mut pop = PopulationBuilder::new(slow_function)
+            .seed(42)
+            .selection(selection)
+            .max_generations(10)
+            .build()
+            .unwrap();
+
+        group.bench_function(selection.to_string(), |b| {
+            b.iter(|| {
+                pop.reset();
+                pop.run(false).unwrap()
+            })
+        });
+
+        // Mesmas gerações, mas reavaliando a população inteira, como faria sem o cache.
+        group.bench_function(format!("{selection}_uncached"), |b| {
+            b.iter(|| {
+                pop.reset();
+                for _ in 0..10 {
+                    pop.step().unwrap();
+                    for genome in (0..).map_while(|i| pop.value(i).ok()) {
+                        black_box(pop.fitness(genome));
+                    }
+                }
+            })
+        });
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, fitness_evaluation, generations);
+criterion_main!(benches);
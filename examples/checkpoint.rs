@@ -0,0 +1,44 @@
+//! Interrompe uma busca na cúbica padrão, salva o estado com o do gerador e retoma a busca
+//! em uma nova população, comparando com a mesma busca feita de uma vez só:
+//!
+//! ```text
+//! cargo run --example checkpoint --features serde
+//! ```
+
+use rand::SeedableRng;
+use rootfn::{functions, Pcg64, PopulationBuilder, Rearrangement, Result};
+
+/// Geração em que a primeira busca é interrompida.
+const CHECKPOINT: u64 = 300;
+
+fn builder(max_generations: u64) -> PopulationBuilder<Pcg64> {
+    PopulationBuilder::new(functions::CUBIC.function)
+        .rng(Pcg64::seed_from_u64(7))
+        .rearrangement(Rearrangement::Genocide)
+        .max_generations(max_generations)
+}
+
+fn main() -> Result<()> {
+    let path = std::env::temp_dir().join("rootfn_checkpoint.json");
+
+    let uninterrupted = builder(5_000).build()?.run(false)?;
+
+    let mut pop = builder(CHECKPOINT).build()?;
+    pop.run(false)?;
+    pop.save_state_with_rng(&path)?;
+
+    let mut resumed = builder(5_000).build()?;
+    resumed.load_state_with_rng(&path)?;
+    let result = resumed.run(false)?;
+
+    println!(
+        "uninterrupted: {:?} in {} generations, resumed: {:?} in {} generations, identical: {}",
+        uninterrupted.root,
+        uninterrupted.generations,
+        result.root,
+        result.generations,
+        uninterrupted.root == result.root && uninterrupted.generations == result.generations
+    );
+
+    Ok(())
+}
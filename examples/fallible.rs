@@ -0,0 +1,30 @@
+//! Busca a raiz de `ln(x) - 1`, definida apenas para `x > 0`.
+//!
+//! A função retorna um `Err` fora do domínio em vez de `NaN`; esses indivíduos recebem a
+//! pior *fitness* possível e a busca converge para `e`.
+
+use rootfn::{PopulationBuilder, Rearrangement, Result};
+
+fn main() -> Result<()> {
+    let f = |x: f64| {
+        if x > 0.0 {
+            Ok(x.ln() - 1.0)
+        } else {
+            Err(format!("ln is not defined at {}", x))
+        }
+    };
+
+    let mut pop = PopulationBuilder::fallible(f)
+        .rearrangement(Rearrangement::Genocide)
+        .expected_roots(vec![vec![std::f64::consts::E]])
+        .seed(1)
+        .build()?;
+
+    let result = pop.run(false)?;
+    println!(
+        "root: {:?}, error: {:?}, converged: {}",
+        result.root, result.root_error, result.converged
+    );
+
+    Ok(())
+}
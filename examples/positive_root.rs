@@ -0,0 +1,26 @@
+//! Busca uma raiz positiva da cúbica padrão penalizando valores negativos.
+//!
+//! Sem a penalidade, o algoritmo pode convergir para qualquer das três raízes
+//! (-4567, 478 e 1240); com ela, só as raízes positivas têm *fitness* perto de zero.
+
+use rootfn::{PopulationBuilder, Rearrangement, Result};
+
+fn main() -> Result<()> {
+    let cubic = |x: f64| (x - 478.0) * (x + 4567.0) * (x - 1240.0);
+
+    let mut pop = PopulationBuilder::new(cubic)
+        .initial_interval(-5000.0..5000.0)
+        .rearrangement(Rearrangement::Genocide)
+        .expected_roots(vec![vec![-4567.0], vec![478.0], vec![1240.0]])
+        .penalty(1e9, |x| (-x[0]).max(0.0))
+        .seed(1)
+        .build()?;
+
+    let result = pop.run(false)?;
+    println!(
+        "root: {:?}, expected: {:?}, converged: {}",
+        result.root, result.expected_root, result.converged
+    );
+
+    Ok(())
+}
@@ -0,0 +1,22 @@
+//! Busca uma raiz de cada função de [`rootfn::functions`] e mostra a distância até a raiz
+//! verdadeira mais próxima.
+
+use rootfn::{functions, PopulationBuilder, Rearrangement, Result};
+
+fn main() -> Result<()> {
+    for test in functions::ALL {
+        let mut pop = PopulationBuilder::new(test.function)
+            .rearrangement(Rearrangement::Genocide)
+            .expected_roots(test.expected_roots())
+            .seed(1)
+            .build()?;
+
+        let result = pop.run(false)?;
+        println!(
+            "{}: root {:?}, expected {:?}, error {:?}, converged: {}",
+            test.name, result.root, result.expected_root, result.root_error, result.converged
+        );
+    }
+
+    Ok(())
+}
@@ -0,0 +1,37 @@
+use crate::{Result, RootfnError};
+
+/// Política de tamanho variável da população, aplicada ao fim de cada geração: cresce `step`
+/// indivíduos, sorteados no intervalo de busca atual, quando a diversidade colapsa, e
+/// diminui `step`, descartando os piores, quando o melhor indivíduo já encontrado melhora
+/// várias gerações seguidas. O tamanho fica sempre entre `min` e `max`.
+///
+/// Uma população grande só é mantida enquanto ajuda a sair de uma estagnação, o que reduz
+/// o número médio de avaliações por geração.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct AdaptiveSize {
+    pub min: usize,
+    pub max: usize,
+    pub step: usize,
+}
+
+impl AdaptiveSize {
+    pub(crate) fn validate(&self, elite_count: usize) -> Result<()> {
+        if self.min == 0 || self.min > self.max {
+            return Err(RootfnError::InvalidConfig(
+                "adaptive size bounds must satisfy 0 < min <= max".to_string(),
+            ));
+        }
+        if self.step == 0 {
+            return Err(RootfnError::InvalidConfig(
+                "adaptive size step must be greater than zero".to_string(),
+            ));
+        }
+        if elite_count > self.min {
+            return Err(RootfnError::InvalidConfig(
+                "elite count must not exceed the minimum adaptive size".to_string(),
+            ));
+        }
+        Ok(())
+    }
+}
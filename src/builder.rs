@@ -0,0 +1,613 @@
+use crate::{
+    config::Config,
+    target::{FitnessFn, MaybeSync, PenaltyFn, Target},
+    AdaptiveSize, Annealing, Crossover, Crowding, DiversityCollapse, Genome, Initialization,
+    LocalSearch, MutationDecay, MutationKind, Norm, Objective, Population, Rearrangement,
+    Rearranger, Result, RootfnError, Selection,
+};
+#[cfg(feature = "complex")]
+use num_complex::Complex;
+use rand::{rngs::StdRng, Rng, SeedableRng};
+use std::{ops::Range, time::Duration};
+
+/// Construtor de [`Population`], com o gerador de números aleatórios `R`.
+pub struct PopulationBuilder<R = StdRng> {
+    target: Target,
+    fitness: FitnessFn,
+    penalty: Option<PenaltyFn>,
+    selection: Selection,
+    rearrangement: Rearrangement,
+    /// Estratégia própria, usada com [`Rearrangement::Custom`].
+    rearranger: Option<Box<dyn Rearranger<R>>>,
+    config: Config,
+    rng: R,
+}
+
+impl PopulationBuilder {
+    /// Cria um construtor para buscar a raiz de `f`.
+    pub fn new(f: impl Fn(f64) -> f64 + MaybeSync + 'static) -> Self {
+        Self::with_target(Target::Scalar(Box::new(f)))
+    }
+
+    /// Cria um construtor para buscar a raiz de uma `f` que pode falhar, por exemplo fora do
+    /// seu domínio.
+    ///
+    /// Um `Err` torna a *fitness* do indivíduo a pior possível, mesmo com uma
+    /// [`PopulationBuilder::fitness`] própria: ele nunca é o melhor e é ignorado nas médias
+    /// da população.
+    pub fn fallible<E>(
+        f: impl Fn(f64) -> std::result::Result<f64, E> + MaybeSync + 'static,
+    ) -> Self {
+        Self::with_target(Target::Fallible(Box::new(move |x| f(x).ok())))
+    }
+
+    /// Cria um construtor para buscar a raiz de uma função de uma variável avaliada em lote:
+    /// `f` recebe vários pontos e retorna o valor da função em cada um, na mesma ordem.
+    ///
+    /// A cada geração, todos os indivíduos que mudaram são avaliados em uma única chamada, o
+    /// que compensa para funções vetorizadas (SIMD, GPU, tabelas). As demais avaliações,
+    /// como as da busca local, chamam `f` com um único ponto.
+    pub fn batch(f: impl Fn(&[f64]) -> Vec<f64> + MaybeSync + 'static) -> Self {
+        Self::with_target(Target::Batch(Box::new(f)))
+    }
+
+    /// Cria um construtor para buscar a raiz do sistema `f: R^n -> R^m`, com `n = dimension`.
+    ///
+    /// A *fitness* de cada indivíduo é a norma de `f`, L2 por padrão; ver
+    /// [`PopulationBuilder::norm`]. `dimension` deve ser maior que zero.
+    pub fn system(dimension: usize, f: impl Fn(&[f64]) -> Vec<f64> + MaybeSync + 'static) -> Self {
+        Self::with_target(Target::System {
+            dimension,
+            function: Box::new(f),
+        })
+    }
+
+    /// Cria um construtor para buscar uma raiz complexa de `f: C -> C`.
+    ///
+    /// Cada indivíduo tem duas componentes, `[re, im]`, cruzadas e mutadas de forma
+    /// independente, e a *fitness* é o módulo de `f` com a norma padrão.
+    #[cfg(feature = "complex")]
+    pub fn complex(f: impl Fn(Complex<f64>) -> Complex<f64> + MaybeSync + 'static) -> Self {
+        Self::with_target(Target::Complex(Box::new(f)))
+    }
+
+    fn with_target(target: Target) -> Self {
+        Self {
+            target,
+            fitness: Box::new(f64::abs),
+            penalty: None,
+            selection: Selection::default(),
+            rearrangement: Rearrangement::default(),
+            rearranger: None,
+            config: Config::default(),
+            rng: StdRng::from_entropy(),
+        }
+    }
+}
+
+impl<R: Rng + MaybeSync> PopulationBuilder<R> {
+    /// Função que converte o valor da função na *fitness* de um indivíduo, onde menor é
+    /// melhor. O padrão é `f64::abs`, que busca raízes; com a identidade, o algoritmo
+    /// minimiza a função.
+    pub fn fitness(mut self, fitness: impl Fn(f64) -> f64 + MaybeSync + 'static) -> Self {
+        self.fitness = Box::new(fitness);
+        self
+    }
+
+    /// Busca raízes, o mínimo ou o máximo da função; ver [`Objective`]. Substitui a função
+    /// de [`PopulationBuilder::fitness`] pela do objetivo.
+    ///
+    /// Com [`Objective::Minimize`] e [`Objective::Maximize`], o `polish` e a fase de gradiente
+    /// de [`MutationKind::Hybrid`], que buscam raízes, não são aplicados.
+    pub fn objective(mut self, objective: Objective) -> Self {
+        self.config.objective = objective;
+        self.fitness = Box::new(objective.fitness());
+        self
+    }
+
+    /// Soma `weight * penalty(x)` à *fitness* de cada indivíduo `x`, favorecendo as raízes
+    /// com penalidade nula, por exemplo `|x| (-x[0]).max(0.0)` para buscar uma raiz positiva.
+    ///
+    /// Como a *fitness* só fica abaixo da tolerância onde a penalidade é quase nula, a
+    /// penalidade deve ser zero nas raízes desejadas.
+    pub fn penalty(
+        mut self,
+        weight: f64,
+        penalty: impl Fn(&[f64]) -> f64 + MaybeSync + 'static,
+    ) -> Self {
+        self.config.penalty_weight = weight;
+        self.penalty = Some(Box::new(penalty));
+        self
+    }
+
+    pub fn selection(mut self, selection: Selection) -> Self {
+        self.selection = selection;
+        self
+    }
+
+    pub fn rearrangement(mut self, rearrangement: Rearrangement) -> Self {
+        self.rearrangement = rearrangement;
+        self.rearranger = None;
+        self
+    }
+
+    /// Usa uma estratégia de reorganização própria no lugar das pré-definidas.
+    pub fn rearranger(mut self, rearranger: impl Rearranger<R> + 'static) -> Self {
+        self.rearrangement = Rearrangement::Custom;
+        self.rearranger = Some(Box::new(rearranger));
+        self
+    }
+
+    /// Semente do gerador de números aleatórios. Sem ela, a semente vem da entropia do sistema.
+    pub fn seed(mut self, seed: u64) -> Self
+    where
+        R: SeedableRng,
+    {
+        self.rng = R::seed_from_u64(seed);
+        self
+    }
+
+    /// Troca o gerador de números aleatórios por `rng`, por exemplo um gerador mais rápido
+    /// que o [`StdRng`] ou um com sequência conhecida. Substitui a semente definida antes.
+    ///
+    /// Como o [`Rearranger`] depende do tipo do gerador, um definido antes com
+    /// [`PopulationBuilder::rearranger`] é descartado; chame este método primeiro.
+    pub fn rng<S: Rng + MaybeSync>(self, rng: S) -> PopulationBuilder<S> {
+        PopulationBuilder {
+            target: self.target,
+            fitness: self.fitness,
+            penalty: self.penalty,
+            selection: self.selection,
+            rearrangement: self.rearrangement,
+            rearranger: None,
+            config: self.config,
+            rng,
+        }
+    }
+
+    /// Intervalo de onde são sorteados os indivíduos da primeira geração, e intervalo de
+    /// busca inicial do genocídio. Deve ser finito e não vazio.
+    ///
+    /// Restringir a busca a um intervalo onde se sabe haver uma raiz reduz bastante o número
+    /// de gerações.
+    pub fn initial_interval(mut self, interval: Range<f64>) -> Self {
+        self.config.initial_interval = interval;
+        self
+    }
+
+    /// Um intervalo inicial para cada dimensão, no lugar do
+    /// [`PopulationBuilder::initial_interval`] compartilhado, para sistemas cujas variáveis
+    /// têm escalas muito diferentes. Deve ter um intervalo finito e não vazio por dimensão.
+    ///
+    /// Cada componente dos indivíduos é sorteada no seu intervalo, e o genocídio e o
+    /// reinício mantêm um intervalo de busca por dimensão.
+    pub fn initial_intervals(mut self, intervals: Vec<Range<f64>>) -> Self {
+        self.config.initial_intervals = Some(intervals);
+        self
+    }
+
+    /// Deixa o tamanho da população variar entre `min` e `max` ao longo da execução; ver
+    /// [`AdaptiveSize`]. A primeira geração continua com `population_size` indivíduos.
+    /// Desligado por padrão.
+    pub fn adaptive_size(mut self, adaptive_size: AdaptiveSize) -> Self {
+        self.config.adaptive_size = Some(adaptive_size);
+        self
+    }
+
+    /// Como a primeira geração, e a população sorteada de novo pelo reinício e pelo
+    /// genocídio, é espalhada no intervalo de busca. O padrão é [`Initialization::Uniform`].
+    pub fn initialization(mut self, initialization: Initialization) -> Self {
+        self.config.initialization = initialization;
+        self
+    }
+
+    /// Número de indivíduos na população. Deve ser maior que zero.
+    pub fn population_size(mut self, size: usize) -> Self {
+        self.config.population_size = size;
+        self
+    }
+
+    /// Número de melhores indivíduos que passam sem alteração para a próxima geração.
+    pub fn elite_count(mut self, count: usize) -> Self {
+        self.config.elite_count = count;
+        self
+    }
+
+    /// Começa a busca a partir de `individuals`, por exemplo em torno de uma estimativa
+    /// conhecida da raiz. Se forem menos que o tamanho da população, o restante é sorteado
+    /// no intervalo inicial; os que passarem do tamanho são descartados.
+    pub fn initial_population(mut self, individuals: Vec<Genome>) -> Self {
+        self.config.initial_population = individuals;
+        self
+    }
+
+    /// Raízes conhecidas da função. Cada [`SolveResult`](crate::SolveResult) passa a
+    /// indicar a mais próxima do resultado e a distância até ela.
+    pub fn expected_roots(mut self, roots: Vec<Genome>) -> Self {
+        self.config.expected_roots = roots;
+        self
+    }
+
+    /// Operador de cruzamento usado para gerar os filhos.
+    pub fn crossover(mut self, crossover: Crossover) -> Self {
+        self.config.crossover = crossover;
+        self
+    }
+
+    /// Tipo de mutação aplicada aos filhos. Os parâmetros são verificados em
+    /// [`PopulationBuilder::build`]; o intervalo de [`MutationKind::Uniform`], por exemplo,
+    /// deve ser finito e ter largura positiva.
+    pub fn mutation(mut self, mutation: MutationKind) -> Self {
+        self.config.mutation = mutation;
+        self
+    }
+
+    /// Reduz a mutação à medida que a *fitness* se aproxima da tolerância, seguindo `decay`.
+    pub fn adaptive_mutation(mut self, decay: MutationDecay) -> Self {
+        self.config.adaptive_mutation = Some(decay);
+        self
+    }
+
+    /// Depois da mutação, mantém um filho que ficou pior com a probabilidade do critério de
+    /// Metropolis, com a temperatura diminuindo a cada geração; ver [`Annealing`]. Cada filho
+    /// passa a ser avaliado antes e depois da mutação. Desligado por padrão.
+    pub fn annealing(mut self, annealing: Annealing) -> Self {
+        self.config.annealing = Some(annealing);
+        self
+    }
+
+    /// A cada geração, antes da reprodução, aplica [`Population::local_search`] a cada
+    /// indivíduo da elite. Acelera bastante a convergência em funções suaves, ao custo de
+    /// até `2 * dimension * steps` avaliações por indivíduo. Desligado por padrão;
+    /// [`LocalSearch::default`] tem parâmetros razoáveis.
+    pub fn local_search(mut self, local_search: LocalSearch) -> Self {
+        self.config.local_search = Some(local_search);
+        self
+    }
+
+    /// Penaliza, na seleção, os indivíduos com muitos vizinhos a menos de `radius`, para que
+    /// a população se espalhe por várias raízes em vez de convergir para uma só; ver
+    /// [`Crowding`]. Vale para a roleta, a SUS, o *ranking* e o torneio. Custa `O(n²)`
+    /// distâncias por geração. Desligado por padrão.
+    ///
+    /// Funciona melhor com um *crossover* que não leve os filhos para o meio dos pais, como o
+    /// [`Crossover::BlxAlpha`]: a média aritmética de pais em raízes diferentes desfaz os
+    /// grupos.
+    pub fn crowding(mut self, crowding: Crowding) -> Self {
+        self.config.crowding = Some(crowding);
+        self
+    }
+
+    pub fn max_generations(mut self, generations: u64) -> Self {
+        self.config.max_generations = generations;
+        self
+    }
+
+    /// Encerra a execução depois de `duration`, retornando o melhor indivíduo encontrado até
+    /// então. Coexiste com `max_generations`: vale o limite que for atingido primeiro.
+    pub fn max_duration(mut self, duration: Duration) -> Self {
+        self.config.max_duration = Some(duration);
+        self
+    }
+
+    /// *Fitness* abaixo da qual a execução é considerada convergida. Deve ser positiva.
+    pub fn fitness_tolerance(mut self, tolerance: f64) -> Self {
+        self.config.fitness_tolerance = tolerance;
+        self
+    }
+
+    /// Máxima diferença entre dois *best* consecutivos para contar como estagnação.
+    pub fn best_delta(mut self, delta: f64) -> Self {
+        self.config.best_delta = delta;
+        self
+    }
+
+    /// Conta como estagnação o melhor indivíduo se mover menos que `delta` vezes a sua norma,
+    /// isto é, `|best - last_best| / |last_best| < delta`, no lugar de `best_delta`. Com isso
+    /// o genocídio passa a depender da escala relativa, e não da absoluta: raízes grandes
+    /// estagnam com movimentos maiores que `best_delta`, e raízes muito pequenas não estagnam
+    /// com qualquer movimento menor que ele. Perto de zero vale o piso `f64::MIN_POSITIVE`.
+    pub fn relative_delta(mut self, delta: f64) -> Self {
+        self.config.relative_delta = Some(delta);
+        self
+    }
+
+    /// Número de gerações estagnadas até aplicar o genocídio ou o reinício.
+    pub fn counter_genocide(mut self, counter: u8) -> Self {
+        self.config.counter_genocide = counter;
+        self
+    }
+
+    /// Com `true`, o genocídio coloca o `global_best` no lugar de um dos indivíduos sorteados,
+    /// como o reinício já faz, então a melhor *fitness* da população logo depois de um
+    /// genocídio nunca é pior que a do `global_best`. Desligado por padrão.
+    pub fn genocide_keeps_best(mut self, keep: bool) -> Self {
+        self.config.genocide_keeps_best = keep;
+        self
+    }
+
+    /// Número de piores indivíduos substituídos a cada predação aleatória.
+    pub fn predation_count(mut self, count: usize) -> Self {
+        self.config.predation_count = count;
+        self
+    }
+
+    /// Encerra a execução se o `global_best` não melhorar por `generations` gerações
+    /// seguidas, sem esperar `max_generations` quando a tolerância é claramente
+    /// inalcançável, como em funções sem raiz. O resultado tem `converged == false` e
+    /// `stalled_out == true`. Vale junto com `max_generations`, o que for atingido primeiro.
+    pub fn min_improvement_generations(mut self, generations: u64) -> Self {
+        self.config.min_improvement_generations = Some(generations);
+        self
+    }
+
+    /// Depois de `generations` gerações seguidas com o melhor indivíduo parado, aumenta a
+    /// mutação e aplica a predação aleatória, qualquer que seja o [`Rearrangement`].
+    pub fn plateau_length(mut self, generations: u64) -> Self {
+        self.config.plateau_length = Some(generations);
+        self
+    }
+
+    /// Busca apenas soluções inteiras: os indivíduos são arredondados depois da mutação e
+    /// do cruzamento, e a execução para quando o melhor inteiro deixa de melhorar por várias
+    /// gerações.
+    pub fn discrete(mut self, discrete: bool) -> Self {
+        self.config.discrete = discrete;
+        self
+    }
+
+    /// Distância mínima entre duas raízes distintas em [`Population::find_all_roots`].
+    pub fn root_epsilon(mut self, epsilon: f64) -> Self {
+        self.config.root_epsilon = epsilon;
+        self
+    }
+
+    /// Distância máxima entre as raízes de um mesmo agrupamento em
+    /// [`TrialStats::clusters`](crate::TrialStats::clusters). O padrão é `1e-3`.
+    pub fn cluster_radius(mut self, radius: f64) -> Self {
+        self.config.cluster_radius = radius;
+        self
+    }
+
+    /// Norma usada para reduzir o resíduo de sistemas e funções complexas a um valor. O
+    /// padrão é [`Norm::L2`]; [`Norm::LInf`] faz a tolerância valer para cada equação.
+    pub fn norm(mut self, norm: Norm) -> Self {
+        self.config.norm = norm;
+        self
+    }
+
+    /// O que fazer quando a diversidade da população cai abaixo de `best_delta`.
+    pub fn diversity_collapse(mut self, behavior: DiversityCollapse) -> Self {
+        self.config.diversity_collapse = behavior;
+        self
+    }
+
+    /// Depois de cada geração, sorteia de novo no intervalo de busca atual todo indivíduo a
+    /// menos de `best_delta` de um anterior, evitando que a população se encha de cópias do
+    /// melhor sem precisar de um genocídio.
+    pub fn deduplicate(mut self, deduplicate: bool) -> Self {
+        self.config.deduplicate = deduplicate;
+        self
+    }
+
+    /// Guarda as estatísticas de cada geração, permitindo exportá-las com
+    /// [`Population::export_csv`]. Desligado por padrão para economizar memória.
+    pub fn record_history(mut self, record: bool) -> Self {
+        self.config.record_history = record;
+        self
+    }
+
+    /// Guarda todos os indivíduos de cada geração, acessíveis por [`Population::snapshots`],
+    /// por exemplo para animar a convergência. Desligado por padrão: o custo de memória é
+    /// `population_size` indivíduos por geração.
+    pub fn record_snapshots(mut self, record: bool) -> Self {
+        self.config.record_snapshots = record;
+        self
+    }
+
+    /// Mede o tempo de cada geração em
+    /// [`GenerationStats::duration`](crate::GenerationStats::duration), por exemplo para
+    /// encontrar as gerações mais caras, como as do genocídio, junto com
+    /// [`PopulationBuilder::record_history`]. Desligado por padrão.
+    pub fn time_generations(mut self, enabled: bool) -> Self {
+        self.config.time_generations = enabled;
+        self
+    }
+
+    /// Soma o melhor *fitness* de cada geração em
+    /// [`SolveResult::convergence_area`](crate::SolveResult::convergence_area), um único
+    /// número para comparar a velocidade de convergência de configurações diferentes.
+    /// Desligado por padrão.
+    pub fn convergence_area(mut self, enabled: bool) -> Self {
+        self.config.convergence_area = enabled;
+        self
+    }
+
+    /// Refina o resultado final com alguns passos do método de Newton, ver
+    /// [`Population::polish`].
+    pub fn polish(mut self, polish: bool) -> Self {
+        self.config.polish = polish;
+        self
+    }
+
+    /// Diretório onde [`Population::run`] salva os gráficos, criado caso não exista. O padrão
+    /// é [`PLOT_DIR`](crate::plot::PLOT_DIR).
+    #[cfg(feature = "plot")]
+    pub fn plot_dir(mut self, dir: impl Into<std::path::PathBuf>) -> Self {
+        self.config.plot_dir = dir.into();
+        self
+    }
+
+    /// Restringe cada componente dos indivíduos a `domain`, incluindo os extremos.
+    ///
+    /// Útil para funções definidas apenas em parte da reta, como `ln` ou `sqrt`.
+    pub fn domain(mut self, domain: Range<f64>) -> Self {
+        self.config.domain = Some(domain);
+        self
+    }
+
+    /// Um domínio para cada dimensão, no lugar do [`PopulationBuilder::domain`] compartilhado,
+    /// como em [`PopulationBuilder::initial_intervals`]. Deve ter um domínio por dimensão.
+    pub fn domains(mut self, domains: Vec<Range<f64>>) -> Self {
+        self.config.domains = Some(domains);
+        self
+    }
+
+    /// Valida a configuração e cria a população.
+    pub fn build(self) -> Result<Population<R>> {
+        self.config.validate()?;
+        self.selection.validate(self.config.population_size)?;
+        if let Some(adaptive_size) = &self.config.adaptive_size {
+            self.selection.validate(adaptive_size.min)?;
+        }
+
+        let dimension = self.target.dimension();
+        if dimension == 0 {
+            return Err(RootfnError::InvalidConfig(
+                "dimension must be greater than zero".to_string(),
+            ));
+        }
+
+        if self
+            .config
+            .initial_population
+            .iter()
+            .any(|genome| genome.len() != dimension)
+        {
+            return Err(RootfnError::InvalidConfig(format!(
+                "initial individuals must have dimension {}",
+                dimension
+            )));
+        }
+
+        if let Some(intervals) = &self.config.initial_intervals {
+            if intervals.len() != dimension {
+                return Err(RootfnError::InvalidConfig(format!(
+                    "expected {} initial intervals, one per dimension, got {}",
+                    dimension,
+                    intervals.len()
+                )));
+            }
+        }
+
+        if let Some(domains) = &self.config.domains {
+            if domains.len() != dimension {
+                return Err(RootfnError::InvalidConfig(format!(
+                    "expected {} domains, one per dimension, got {}",
+                    dimension,
+                    domains.len()
+                )));
+            }
+        }
+
+        if self
+            .config
+            .expected_roots
+            .iter()
+            .any(|root| root.len() != dimension)
+        {
+            return Err(RootfnError::InvalidConfig(format!(
+                "expected roots must have dimension {}",
+                dimension
+            )));
+        }
+
+        let rearranger = match self.rearranger {
+            Some(rearranger) => rearranger,
+            None => self
+                .rearrangement
+                .rearranger(self.config.counter_genocide)
+                .ok_or_else(|| {
+                    RootfnError::InvalidConfig(
+                        "custom rearrangement requires a rearranger".to_string(),
+                    )
+                })?,
+        };
+
+        Ok(Population::from_parts(
+            self.target,
+            self.fitness,
+            self.penalty,
+            self.selection,
+            self.rearrangement,
+            rearranger,
+            self.config,
+            self.rng,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::MAX_GENERATIONS;
+
+    #[test]
+    fn rejects_zero_dimension() {
+        let result = PopulationBuilder::system(0, |_| Vec::new()).build();
+        assert!(matches!(result, Err(RootfnError::InvalidConfig(_))));
+    }
+
+    #[test]
+    fn stalls_out_without_a_root() {
+        let result = PopulationBuilder::new(|x| x * x + 1.0)
+            .min_improvement_generations(50)
+            .seed(1)
+            .build()
+            .unwrap()
+            .run(false)
+            .unwrap();
+
+        assert!(!result.converged);
+        assert!(result.stalled_out);
+        assert!(result.generations < MAX_GENERATIONS);
+    }
+
+    #[test]
+    fn rejects_zero_width_intervals() {
+        let mutation = PopulationBuilder::new(|x| x)
+            .mutation(MutationKind::Uniform {
+                interval: 0.0..0.0,
+                rate: 1.0,
+            })
+            .build();
+        assert!(matches!(mutation, Err(RootfnError::InvalidConfig(_))));
+
+        let initial = PopulationBuilder::new(|x| x)
+            .initial_interval(1.0..1.0)
+            .build();
+        assert!(matches!(initial, Err(RootfnError::InvalidConfig(_))));
+    }
+
+    #[test]
+    fn rejects_intervals_with_an_overflowing_width() {
+        let mutation = PopulationBuilder::new(|x| x)
+            .mutation(MutationKind::Uniform {
+                interval: -1e308..1e308,
+                rate: 1.0,
+            })
+            .build();
+        assert!(matches!(mutation, Err(RootfnError::InvalidConfig(_))));
+
+        let initial = PopulationBuilder::new(|x| x)
+            .initial_interval(-1e308..1e308)
+            .build();
+        assert!(matches!(initial, Err(RootfnError::InvalidConfig(_))));
+    }
+
+    #[test]
+    fn fallible_errors_are_the_worst_fitness_with_a_custom_fitness() {
+        let pop = PopulationBuilder::fallible(|x| if x < 0.0 { Err(()) } else { Ok(x - 2.0) })
+            .fitness(|v| v.abs().min(1.0))
+            .build()
+            .unwrap();
+        assert_eq!(pop.fitness(&[-1.0]), f64::INFINITY);
+        assert_eq!(pop.fitness(&[10.0]), 1.0);
+    }
+
+    #[test]
+    fn rejects_empty_population() {
+        let result = PopulationBuilder::new(|x| x).population_size(0).build();
+        assert!(matches!(result, Err(RootfnError::InvalidConfig(_))));
+    }
+}
@@ -0,0 +1,293 @@
+use crate::{
+    AdaptiveSize, Annealing, Crossover, Crowding, DiversityCollapse, Genome, Initialization,
+    LocalSearch, MutationDecay, MutationKind, Norm, Objective, Result, RootfnError,
+};
+use std::{ops::Range, time::Duration};
+
+/// Intervalo inicial para a primeira geração, aplicado a cada dimensão.
+pub const INITIAL_INTERVAL: Range<f64> = -100.0..100.0;
+
+/// Número de indivíduos na população.
+pub const POPULATION_SIZE: usize = 100;
+
+/// Taxa de mutação.
+/// **NÃO** é em porcentagem.
+pub const MUTATION_RATE: f64 = 0.001;
+
+/// Intervalo para mutação, aplicado a cada dimensão.
+pub const MUTATION_INTERVAL: Range<f64> = -10.0..10.0;
+
+/// Número máximo de gerações.
+pub const MAX_GENERATIONS: u64 = 100_000;
+
+/// Tolerância permitida para a função de *fitness*.
+pub const FITNESS_TOLERANCE: f64 = 1e-4;
+
+/// Número de melhores indivíduos preservados sem alteração entre gerações.
+pub const ELITE_COUNT: usize = 1;
+
+/// Número de piores indivíduos substituídos a cada predação aleatória.
+pub const PREDATION_COUNT: usize = 1;
+
+/// Peso padrão da penalidade somada à *fitness*.
+pub const PENALTY_WEIGHT: f64 = 1.0;
+
+/// Máxima diferença entre dois *best* consecutivos para aplicar o genocídio.
+pub const BEST_DELTA: f64 = 1e-8;
+
+/// Movimento abaixo do qual o melhor indivíduo estagna com `relative_delta`, bem menor que
+/// [`BEST_DELTA`], para que uma raiz em zero também estagne.
+pub const RELATIVE_DELTA_FLOOR: f64 = f64::MIN_POSITIVE;
+
+/// Número de gerações seguidas sem melhora até aplicar o genocídio.
+pub const COUNTER_GENOCIDE: u8 = 5;
+
+/// Distância mínima entre duas raízes para que sejam consideradas distintas.
+pub const ROOT_EPSILON: f64 = 1e-6;
+
+/// Distância máxima entre uma raiz e a média de um agrupamento de
+/// [`TrialStats::clusters`](crate::TrialStats::clusters) para que ela entre nele.
+pub const CLUSTER_RADIUS: f64 = 1e-3;
+
+/// Hiperparâmetros do algoritmo.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub(crate) struct Config {
+    pub initial_interval: Range<f64>,
+    /// Intervalo inicial de cada dimensão. Sem ele, `initial_interval` vale para todas.
+    pub initial_intervals: Option<Vec<Range<f64>>>,
+    pub initialization: Initialization,
+    /// Indivíduos da primeira geração, completados com sorteios em `initial_interval` até
+    /// `population_size`.
+    pub initial_population: Vec<Genome>,
+    pub population_size: usize,
+    /// Raízes conhecidas, usadas para identificar a encontrada em cada execução.
+    pub expected_roots: Vec<Genome>,
+    pub elite_count: usize,
+    pub crossover: Crossover,
+    pub mutation: MutationKind,
+    /// Quando presente, a mutação diminui à medida que a *fitness* melhora.
+    pub adaptive_mutation: Option<MutationDecay>,
+    /// Quando presente, aceita mutações que pioram o filho pelo critério de Metropolis.
+    pub annealing: Option<Annealing>,
+    /// Quando presente, refina a elite com uma busca local a cada geração.
+    pub local_search: Option<LocalSearch>,
+    /// Quando presente, penaliza na seleção os indivíduos com muitos vizinhos próximos.
+    pub crowding: Option<Crowding>,
+    /// Quando presente, o tamanho da população varia entre os limites dela.
+    pub adaptive_size: Option<AdaptiveSize>,
+    pub max_generations: u64,
+    /// Tempo máximo de uma execução.
+    pub max_duration: Option<Duration>,
+    pub fitness_tolerance: f64,
+    /// Busca de raízes, mínimo ou máximo. A tolerância só vale para raízes.
+    pub objective: Objective,
+    pub best_delta: f64,
+    /// Fração da norma do melhor indivíduo abaixo da qual o movimento dele conta como
+    /// estagnação, no lugar de `best_delta`.
+    pub relative_delta: Option<f64>,
+    pub counter_genocide: u8,
+    /// Mantém uma cópia do `global_best` na população sorteada pelo genocídio.
+    pub genocide_keeps_best: bool,
+    pub predation_count: usize,
+    /// Gerações seguidas sem melhora do `global_best` até encerrar a execução.
+    pub min_improvement_generations: Option<u64>,
+    /// Gerações estagnadas até chamar [`Population::on_plateau`](crate::Population::on_plateau).
+    pub plateau_length: Option<u64>,
+    pub root_epsilon: f64,
+    pub cluster_radius: f64,
+    /// Peso da penalidade na *fitness*, quando há uma.
+    pub penalty_weight: f64,
+    /// Norma do resíduo de sistemas e funções complexas.
+    pub norm: Norm,
+    pub diversity_collapse: DiversityCollapse,
+    /// Sorteia de novo os indivíduos a menos de `best_delta` de outro a cada geração.
+    pub deduplicate: bool,
+    /// Guarda as estatísticas de cada geração em
+    /// [`Population::history`](crate::Population::history).
+    pub record_history: bool,
+    /// Guarda os indivíduos de cada geração em
+    /// [`Population::snapshots`](crate::Population::snapshots).
+    pub record_snapshots: bool,
+    /// Mede o tempo de cada geração em [`GenerationStats`](crate::GenerationStats).
+    pub time_generations: bool,
+    /// Calcula a área sob a curva do melhor *fitness* em [`SolveResult`](crate::SolveResult).
+    pub convergence_area: bool,
+    /// Refina o resultado final com o método de Newton.
+    pub polish: bool,
+    /// Intervalo fechado ao qual cada componente dos indivíduos é restrito.
+    pub domain: Option<Range<f64>>,
+    /// Domínio de cada dimensão. Sem ele, `domain` vale para todas.
+    pub domains: Option<Vec<Range<f64>>>,
+    /// Diretório dos gráficos salvos por [`Population::run`](crate::Population::run).
+    #[cfg(feature = "plot")]
+    pub plot_dir: std::path::PathBuf,
+    /// Arredonda os indivíduos para inteiros, buscando apenas soluções inteiras.
+    pub discrete: bool,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            initial_interval: INITIAL_INTERVAL,
+            initial_intervals: None,
+            initialization: Initialization::Uniform,
+            initial_population: Vec::new(),
+            population_size: POPULATION_SIZE,
+            expected_roots: Vec::new(),
+            elite_count: ELITE_COUNT,
+            crossover: Crossover::Arithmetic,
+            mutation: MutationKind::Uniform {
+                interval: MUTATION_INTERVAL,
+                rate: MUTATION_RATE,
+            },
+            adaptive_mutation: None,
+            annealing: None,
+            local_search: None,
+            crowding: None,
+            adaptive_size: None,
+            max_generations: MAX_GENERATIONS,
+            max_duration: None,
+            fitness_tolerance: FITNESS_TOLERANCE,
+            objective: Objective::MinimizeAbs,
+            best_delta: BEST_DELTA,
+            relative_delta: None,
+            counter_genocide: COUNTER_GENOCIDE,
+            genocide_keeps_best: false,
+            predation_count: PREDATION_COUNT,
+            min_improvement_generations: None,
+            plateau_length: None,
+            root_epsilon: ROOT_EPSILON,
+            cluster_radius: CLUSTER_RADIUS,
+            penalty_weight: PENALTY_WEIGHT,
+            norm: Norm::default(),
+            diversity_collapse: DiversityCollapse::Continue,
+            deduplicate: false,
+            record_history: false,
+            record_snapshots: false,
+            time_generations: false,
+            convergence_area: false,
+            polish: false,
+            domain: None,
+            domains: None,
+            #[cfg(feature = "plot")]
+            plot_dir: crate::plot::PLOT_DIR.into(),
+            discrete: false,
+        }
+    }
+}
+
+impl Config {
+    /// Intervalo inicial de cada uma das `dimension` dimensões.
+    pub fn initial_intervals(&self, dimension: usize) -> Vec<Range<f64>> {
+        match &self.initial_intervals {
+            Some(intervals) => intervals.clone(),
+            None => vec![self.initial_interval.clone(); dimension],
+        }
+    }
+
+    /// Domínio da dimensão `index`, se houver.
+    pub fn domain(&self, index: usize) -> Option<&Range<f64>> {
+        match &self.domains {
+            Some(domains) => domains.get(index),
+            None => self.domain.as_ref(),
+        }
+    }
+
+    /// Verifica se os hiperparâmetros formam uma configuração válida.
+    pub fn validate(&self) -> Result<()> {
+        if self.population_size == 0 {
+            return Err(invalid("population size must be greater than zero"));
+        }
+
+        let intervals =
+            std::iter::once(&self.initial_interval).chain(self.initial_intervals.iter().flatten());
+        for interval in intervals {
+            // A largura também precisa ser finita, senão o sorteio da população estoura.
+            if !(interval.end - interval.start).is_finite() || interval.start >= interval.end {
+                return Err(invalid(
+                    "initial interval must have a finite width with start less than end",
+                ));
+            }
+        }
+
+        if self.elite_count > self.population_size {
+            return Err(invalid("elite count must not exceed the population size"));
+        }
+
+        if self.fitness_tolerance.is_nan() || self.fitness_tolerance <= 0.0 {
+            return Err(invalid("fitness tolerance must be positive"));
+        }
+
+        self.crossover.validate()?;
+        self.mutation.validate()?;
+        if let Some(annealing) = &self.annealing {
+            annealing.validate()?;
+        }
+        if let Some(local_search) = &self.local_search {
+            local_search.validate()?;
+        }
+        if let Some(crowding) = &self.crowding {
+            crowding.validate()?;
+        }
+        if let Some(adaptive_size) = &self.adaptive_size {
+            adaptive_size.validate(self.elite_count)?;
+        }
+
+        if self.best_delta.is_nan() || self.best_delta < 0.0 {
+            return Err(invalid("best delta must be non-negative"));
+        }
+
+        if let Some(relative) = self.relative_delta {
+            if !(relative >= 0.0 && relative.is_finite()) {
+                return Err(invalid("relative delta must be finite and non-negative"));
+            }
+        }
+
+        if self.counter_genocide == 0 {
+            return Err(invalid("genocide counter must be greater than zero"));
+        }
+
+        if self.predation_count == 0 {
+            return Err(invalid("predation count must be greater than zero"));
+        }
+
+        if self.min_improvement_generations == Some(0) {
+            return Err(invalid(
+                "minimum improvement generations must be greater than zero",
+            ));
+        }
+
+        if self.plateau_length == Some(0) {
+            return Err(invalid("plateau length must be greater than zero"));
+        }
+
+        for domain in self.domain.iter().chain(self.domains.iter().flatten()) {
+            if domain.start.is_nan() || domain.end.is_nan() || domain.start > domain.end {
+                return Err(invalid("domain start must not be greater than its end"));
+            }
+
+            if self.discrete && domain.start.ceil() > domain.end.floor() {
+                return Err(invalid("domain must contain an integer in discrete mode"));
+            }
+        }
+
+        if self.root_epsilon.is_nan() || self.root_epsilon < 0.0 {
+            return Err(invalid("root epsilon must be non-negative"));
+        }
+
+        if !(self.cluster_radius >= 0.0 && self.cluster_radius.is_finite()) {
+            return Err(invalid("cluster radius must be finite and non-negative"));
+        }
+
+        if !(self.penalty_weight.is_finite() && self.penalty_weight >= 0.0) {
+            return Err(invalid("penalty weight must be finite and non-negative"));
+        }
+
+        Ok(())
+    }
+}
+
+fn invalid(message: &str) -> RootfnError {
+    RootfnError::InvalidConfig(message.to_string())
+}
@@ -0,0 +1,164 @@
+use crate::{genome::midpoint, Genome, Result, RootfnError};
+use rand::Rng;
+
+/// Somado às *fitness* nos pesos de seleção e no peso da média ponderada, evitando divisão
+/// por zero.
+pub(crate) const WEIGHT_EPSILON: f64 = 1e-12;
+
+/// Operador que combina dois pais em um filho, componente a componente.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum Crossover {
+    /// Média aritmética dos pais.
+    Arithmetic,
+    /// Média ponderada dos pais, com peso maior para o de menor *fitness*: o filho é
+    /// `w * melhor + (1 - w) * pior`, com `w` proporcional à *fitness* do pior pai.
+    WeightedArithmetic,
+    /// *Blend crossover*: sorteia o filho no intervalo entre os pais, estendido por
+    /// `alpha` vezes a distância entre eles em cada lado.
+    BlxAlpha { alpha: f64 },
+    /// *Simulated binary crossover*: quanto maior `eta`, mais perto dos pais fica o filho.
+    SimulatedBinary { eta: f64 },
+}
+
+impl Crossover {
+    /// Gera um filho a partir de `a` e `b`. `weight` é o peso de `a` na média ponderada e só
+    /// é usado por [`Crossover::WeightedArithmetic`].
+    pub(crate) fn apply(&self, rng: &mut impl Rng, a: &[f64], b: &[f64], weight: f64) -> Genome {
+        match self {
+            Crossover::Arithmetic => midpoint(a, b),
+            Crossover::WeightedArithmetic => a
+                .iter()
+                .zip(b)
+                .map(|(a, b)| weight * a + (1.0 - weight) * b)
+                .collect(),
+            Crossover::BlxAlpha { alpha } => a
+                .iter()
+                .zip(b)
+                .map(|(a, b)| {
+                    let d = (a - b).abs();
+                    // Limitados aos valores finitos, já que `alpha * d` pode estourar.
+                    let low = (a.min(*b) - alpha * d).max(f64::MIN);
+                    let high = (a.max(*b) + alpha * d).min(f64::MAX);
+                    if low >= high {
+                        a.min(*b)
+                    } else if (high - low).is_finite() {
+                        rng.gen_range(low..high)
+                    } else {
+                        // A largura não cabe em um `f64`, mas a metade dela sim.
+                        let (center, half_width) = (low / 2.0 + high / 2.0, high / 2.0 - low / 2.0);
+                        (center + half_width * rng.gen_range(-1.0..1.0)).clamp(low, high)
+                    }
+                })
+                .collect(),
+            Crossover::SimulatedBinary { eta } => a
+                .iter()
+                .zip(b)
+                .map(|(a, b)| {
+                    let u: f64 = rng.gen();
+                    let beta = if u <= 0.5 {
+                        (2.0 * u).powf(1.0 / (eta + 1.0))
+                    } else {
+                        (1.0 / (2.0 * (1.0 - u))).powf(1.0 / (eta + 1.0))
+                    };
+
+                    if rng.gen_bool(0.5) {
+                        0.5 * ((1.0 + beta) * a + (1.0 - beta) * b)
+                    } else {
+                        0.5 * ((1.0 - beta) * a + (1.0 + beta) * b)
+                    }
+                })
+                .collect(),
+        }
+    }
+
+    pub(crate) fn validate(&self) -> Result<()> {
+        let valid = match self {
+            Crossover::Arithmetic | Crossover::WeightedArithmetic => true,
+            Crossover::BlxAlpha { alpha } => *alpha >= 0.0 && alpha.is_finite(),
+            Crossover::SimulatedBinary { eta } => *eta >= 0.0 && eta.is_finite(),
+        };
+
+        if valid {
+            Ok(())
+        } else {
+            Err(RootfnError::InvalidConfig(
+                "crossover parameters must be finite and non-negative".to_string(),
+            ))
+        }
+    }
+}
+
+/// Peso do pai com *fitness* `a` na média ponderada com o pai de *fitness* `b`.
+///
+/// É maior que `0.5` quando `a < b` e igual a `0.5` quando as duas são iguais. *Fitness*
+/// negativas são deslocadas para que a menor seja zero.
+pub(crate) fn fitter_weight(a: f64, b: f64) -> f64 {
+    let offset = a.min(b).min(0.0);
+    let (a, b) = (a - offset + WEIGHT_EPSILON, b - offset + WEIGHT_EPSILON);
+    let weight = b / (a + b);
+    if weight.is_finite() {
+        weight
+    } else if a < b {
+        1.0
+    } else if b < a {
+        0.0
+    } else {
+        0.5
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+    use rand::{rngs::StdRng, SeedableRng};
+
+    /// Valores negativos e positivos de magnitudes muito diferentes.
+    fn value() -> impl Strategy<Value = f64> {
+        prop_oneof![-1e250..1e250, -1e3..1e3_f64, -1e-250..1e-250]
+    }
+
+    proptest! {
+        #[test]
+        fn arithmetic_stays_between_parents(a in value(), b in value()) {
+            let child = Crossover::Arithmetic.apply(&mut StdRng::seed_from_u64(0), &[a], &[b], 0.5);
+            prop_assert!(a.min(b) <= child[0] && child[0] <= a.max(b));
+        }
+
+        #[test]
+        fn blx_alpha_stays_within_extended_interval(
+            a in value(),
+            b in value(),
+            alpha in 0.0..2.0_f64,
+            seed: u64,
+        ) {
+            let crossover = Crossover::BlxAlpha { alpha };
+            let child = crossover.apply(&mut StdRng::seed_from_u64(seed), &[a], &[b], 0.5);
+            let d = (a - b).abs();
+            prop_assert!(a.min(b) - alpha * d <= child[0] && child[0] <= a.max(b) + alpha * d);
+        }
+
+        #[test]
+        fn blx_alpha_is_finite_with_large_alpha(
+            a in -1e308..1e308_f64,
+            b in -1e308..1e308_f64,
+            alpha in 1.0..1e307_f64,
+            seed: u64,
+        ) {
+            let crossover = Crossover::BlxAlpha { alpha };
+            let child = crossover.apply(&mut StdRng::seed_from_u64(seed), &[a], &[b], 0.5);
+            let d = (a - b).abs();
+            prop_assert!(child[0].is_finite());
+            prop_assert!(a.min(b) - alpha * d <= child[0] && child[0] <= a.max(b) + alpha * d);
+        }
+
+        #[test]
+        fn simulated_binary_is_finite(a in value(), b in value(), eta in 0.0..100.0_f64, seed: u64) {
+            let crossover = Crossover::SimulatedBinary { eta };
+            let child = crossover.apply(&mut StdRng::seed_from_u64(seed), &[a], &[b], 0.5);
+            prop_assert!(child[0].is_finite());
+        }
+    }
+}
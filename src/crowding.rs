@@ -0,0 +1,38 @@
+use crate::{Result, RootfnError};
+
+/// Penalidade de aglomeração aplicada antes da seleção, para manter a população espalhada
+/// e encontrar várias raízes ao mesmo tempo.
+///
+/// Para a seleção, cada indivíduo passa a valer a sua posição no *ranking* da *fitness*,
+/// de `1` para o melhor a `n` para o pior, multiplicada por `1 + weight * vizinhos`, onde
+/// `vizinhos` é o número de indivíduos a menos de `radius` dele. Usar a posição e não a
+/// própria *fitness* torna a penalidade independente da escala da função: um grupo grande
+/// em uma raiz perde para indivíduos isolados um pouco piores, que passam a ser escolhidos.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct Crowding {
+    pub radius: f64,
+    pub weight: f64,
+}
+
+impl Crowding {
+    /// Valor usado pela seleção para o indivíduo na posição `rank` do *ranking*, a partir de
+    /// zero, com `neighbors` vizinhos a menos de `radius`.
+    pub(crate) fn penalize(&self, rank: usize, neighbors: usize) -> f64 {
+        (rank + 1) as f64 * (1.0 + self.weight * neighbors as f64)
+    }
+
+    pub(crate) fn validate(&self) -> Result<()> {
+        if !(self.radius > 0.0 && self.radius.is_finite()) {
+            return Err(RootfnError::InvalidConfig(
+                "crowding radius must be finite and positive".to_string(),
+            ));
+        }
+        if !(self.weight >= 0.0 && self.weight.is_finite()) {
+            return Err(RootfnError::InvalidConfig(
+                "crowding weight must be finite and non-negative".to_string(),
+            ));
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,67 @@
+use std::{fmt::Display, io};
+
+/// Erros que podem ocorrer durante a execução do algoritmo.
+#[derive(Debug)]
+pub enum RootfnError {
+    /// Acesso a um indivíduo inexistente.
+    IndexOutOfBounds { index: usize, len: usize },
+    /// A população não possui nenhum indivíduo.
+    EmptyPopulation,
+    /// Nenhum indivíduo tem *fitness* finita, geralmente porque a função não pode ser
+    /// avaliada em nenhum deles.
+    NoFiniteFitness,
+    /// Hiperparâmetros inválidos passados ao [`PopulationBuilder`](crate::PopulationBuilder).
+    InvalidConfig(String),
+    /// Falha ao salvar um gráfico.
+    PlotIo(io::Error),
+    /// Falha de I/O ao exportar dados.
+    Io(io::Error),
+    /// Falha ao serializar em JSON.
+    #[cfg(feature = "serde")]
+    Json(serde_json::Error),
+}
+
+impl Display for RootfnError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RootfnError::IndexOutOfBounds { index, len } => {
+                write!(f, "index {} out of bounds for population of {}", index, len)
+            }
+            RootfnError::EmptyPopulation => write!(f, "population is empty"),
+            RootfnError::NoFiniteFitness => {
+                write!(f, "every individual has a NaN or infinite fitness")
+            }
+            RootfnError::InvalidConfig(message) => write!(f, "invalid configuration: {}", message),
+            RootfnError::PlotIo(e) => write!(f, "could not write plot: {}", e),
+            RootfnError::Io(e) => write!(f, "i/o error: {}", e),
+            #[cfg(feature = "serde")]
+            RootfnError::Json(e) => write!(f, "could not serialize to json: {}", e),
+        }
+    }
+}
+
+impl std::error::Error for RootfnError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            RootfnError::PlotIo(e) | RootfnError::Io(e) => Some(e),
+            #[cfg(feature = "serde")]
+            RootfnError::Json(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for RootfnError {
+    fn from(e: io::Error) -> Self {
+        RootfnError::Io(e)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl From<serde_json::Error> for RootfnError {
+    fn from(e: serde_json::Error) -> Self {
+        RootfnError::Json(e)
+    }
+}
+
+pub type Result<T> = std::result::Result<T, RootfnError>;
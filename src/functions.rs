@@ -0,0 +1,95 @@
+//! Funções de teste com raízes conhecidas analiticamente, para exemplos, *benchmarks* e para
+//! conferir se o algoritmo converge perto das raízes verdadeiras.
+//!
+//! ```text
+//! let mut pop = PopulationBuilder::new(functions::CUBIC.function).build()?;
+//! ```
+
+/// Função de teste e as suas raízes reais, em ordem crescente.
+#[derive(Debug, Clone, Copy)]
+pub struct TestFunction {
+    /// Nome usado pelo [`FunctionRegistry`](crate::FunctionRegistry) e pelo `--function`.
+    pub name: &'static str,
+    pub function: fn(f64) -> f64,
+    pub roots: &'static [f64],
+}
+
+impl TestFunction {
+    /// Raízes no formato de
+    /// [`PopulationBuilder::expected_roots`](crate::PopulationBuilder::expected_roots).
+    pub fn expected_roots(&self) -> Vec<Vec<f64>> {
+        self.roots.iter().map(|root| vec![*root]).collect()
+    }
+}
+
+/// `(x - 478)(x + 4567)(x - 1240)`, a cúbica padrão do binário. Duas das raízes ficam fora
+/// do intervalo inicial padrão.
+pub const CUBIC: TestFunction = TestFunction {
+    name: "cubic",
+    function: |x| (x - 478.0) * (x + 4567.0) * (x - 1240.0),
+    roots: &[-4567.0, 478.0, 1240.0],
+};
+
+/// `x³ + 97x² + 615x - 77625 = (x - 23)(x + 45)(x + 75)`.
+pub const CUBIC_ALT: TestFunction = TestFunction {
+    name: "cubic_alt",
+    function: |x| x.powi(3) + 97.0 * x.powi(2) + 615.0 * x - 77625.0,
+    roots: &[-75.0, -45.0, 23.0],
+};
+
+/// `x² - 5x + 6`.
+pub const QUADRATIC: TestFunction = TestFunction {
+    name: "quadratic",
+    function: |x| x * x - 5.0 * x + 6.0,
+    roots: &[2.0, 3.0],
+};
+
+/// `x³ - 27`.
+pub const CUBE: TestFunction = TestFunction {
+    name: "cube",
+    function: |x| x * x * x - 27.0,
+    roots: &[3.0],
+};
+
+/// `eˣ - 2`, com raiz `ln 2`.
+pub const EXP_MINUS_TWO: TestFunction = TestFunction {
+    name: "exp_minus_two",
+    function: |x| x.exp() - 2.0,
+    roots: &[std::f64::consts::LN_2],
+};
+
+/// Todas as funções deste módulo.
+pub const ALL: &[TestFunction] = &[CUBIC, CUBIC_ALT, QUADRATIC, CUBE, EXP_MINUS_TWO];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{PopulationBuilder, Rearrangement};
+
+    #[test]
+    fn each_function_converges_near_a_true_root() {
+        for test in ALL {
+            // Um intervalo que contém as raízes de todas as funções.
+            let result = PopulationBuilder::new(test.function)
+                .initial_interval(-5000.0..5000.0)
+                .rearrangement(Rearrangement::Genocide)
+                .expected_roots(test.expected_roots())
+                .max_generations(5_000)
+                .seed(1)
+                .build()
+                .unwrap()
+                .run(false)
+                .unwrap();
+
+            assert!(result.converged, "{}", test.name);
+            let error = result.root_error.unwrap();
+            assert!(
+                error < 1e-3,
+                "{}: {:?} is {} from a root",
+                test.name,
+                result.root,
+                error
+            );
+        }
+    }
+}
@@ -0,0 +1,47 @@
+/// Representação de um indivíduo: um ponto em R^n.
+pub type Genome = Vec<f64>;
+
+/// Média aritmética, componente a componente, de dois indivíduos.
+pub(crate) fn midpoint(a: &[f64], b: &[f64]) -> Genome {
+    a.iter().zip(b).map(|(a, b)| (a + b) / 2.0).collect()
+}
+
+/// Norma L2 de um vetor.
+pub(crate) fn norm(v: &[f64]) -> f64 {
+    v.iter().map(|x| x * x).sum::<f64>().sqrt()
+}
+
+/// Distância euclidiana entre dois indivíduos.
+pub(crate) fn distance(a: &[f64], b: &[f64]) -> f64 {
+    a.iter()
+        .zip(b)
+        .map(|(a, b)| (a - b) * (a - b))
+        .sum::<f64>()
+        .sqrt()
+}
+
+/// Norma usada para reduzir um resíduo vetorial a um único valor, em sistemas de equações e
+/// funções complexas. Em funções de uma variável não tem efeito.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum Norm {
+    /// Soma dos valores absolutos.
+    L1,
+    /// Norma euclidiana.
+    #[default]
+    L2,
+    /// Maior valor absoluto, isto é, o maior resíduo.
+    LInf,
+}
+
+impl Norm {
+    /// Norma de `v`.
+    pub(crate) fn apply(&self, v: &[f64]) -> f64 {
+        match self {
+            Norm::L1 => v.iter().map(|x| x.abs()).sum(),
+            Norm::L2 => norm(v),
+            Norm::LInf => v.iter().fold(0.0, |max, x| f64::max(max, x.abs())),
+        }
+    }
+}
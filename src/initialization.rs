@@ -0,0 +1,91 @@
+use crate::Genome;
+use rand::{seq::SliceRandom, Rng};
+use std::ops::Range;
+
+/// Como os indivíduos são espalhados quando toda a população é sorteada de uma vez: na
+/// primeira geração, no reinício e no genocídio.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum Initialization {
+    /// Cada componente é sorteada uniformemente no seu intervalo, de forma independente.
+    #[default]
+    Uniform,
+    /// Amostragem estratificada: o intervalo de cada dimensão é dividido em tantas faixas
+    /// iguais quanto indivíduos, e cada faixa recebe exatamente um deles. Com mais de uma
+    /// dimensão é o *Latin hypercube sampling*. Evita grandes regiões sem nenhum indivíduo.
+    Stratified,
+}
+
+impl Initialization {
+    /// Sorteia `count` indivíduos com cada componente no intervalo correspondente de
+    /// `intervals`.
+    pub(crate) fn sample(
+        &self,
+        rng: &mut impl Rng,
+        count: usize,
+        intervals: &[Range<f64>],
+    ) -> Vec<Genome> {
+        match self {
+            Initialization::Uniform => (0..count).map(|_| individual(rng, intervals)).collect(),
+            Initialization::Stratified => {
+                let mut genomes = vec![Vec::with_capacity(intervals.len()); count];
+                for interval in intervals {
+                    let mut bins: Vec<usize> = (0..count).collect();
+                    bins.shuffle(rng);
+
+                    let width = (interval.end - interval.start) / count as f64;
+                    for (genome, bin) in genomes.iter_mut().zip(bins) {
+                        let offset: f64 = rng.gen();
+                        genome.push(interval.start + width * (bin as f64 + offset));
+                    }
+                }
+                genomes
+            }
+        }
+    }
+}
+
+/// Sorteia um indivíduo com cada componente no intervalo correspondente de `intervals`.
+/// Uma componente cujo intervalo é vazio recebe o início dele.
+pub(crate) fn individual(rng: &mut impl Rng, intervals: &[Range<f64>]) -> Genome {
+    intervals
+        .iter()
+        .map(|interval| {
+            if interval.start < interval.end {
+                rng.gen_range(interval.clone())
+            } else {
+                interval.start
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::{rngs::StdRng, SeedableRng};
+
+    /// Número de faixas de `0.0..1.0` que ficaram sem nenhum indivíduo.
+    fn empty_bins(genomes: &[Genome]) -> usize {
+        let mut filled = vec![false; genomes.len()];
+        for genome in genomes {
+            filled[(genome[0] * genomes.len() as f64) as usize] = true;
+        }
+        filled.into_iter().filter(|filled| !filled).count()
+    }
+
+    #[test]
+    fn stratified_fills_every_bin_unlike_uniform() {
+        let mut rng = StdRng::seed_from_u64(1);
+        let intervals = [0.0..1.0, -5.0..5.0];
+        let stratified = Initialization::Stratified.sample(&mut rng, 100, &intervals);
+        let uniform = Initialization::Uniform.sample(&mut rng, 100, &intervals);
+
+        assert_eq!(empty_bins(&stratified), 0);
+        assert!(empty_bins(&uniform) > 0);
+        for genome in stratified.iter().chain(&uniform) {
+            assert!(intervals.iter().zip(genome).all(|(i, x)| i.contains(x)));
+        }
+    }
+}
@@ -0,0 +1,161 @@
+use crate::{target::MaybeSync, Genome, Population, Result, RootfnError, SolveResult};
+use rand::{rngs::StdRng, Rng};
+#[cfg(feature = "rayon")]
+use rayon::prelude::*;
+use std::time::Instant;
+
+/// Número padrão de gerações entre duas migrações.
+const MIGRATION_EPOCH: u64 = 50;
+
+/// Número padrão de indivíduos enviados por cada ilha a cada migração.
+const MIGRANTS: usize = 1;
+
+/// Modelo de ilhas: várias populações evoluem de forma independente e, a cada `epoch`
+/// gerações, cada uma envia os seus `migrants` melhores indivíduos para a seguinte, em anel,
+/// onde eles substituem os piores.
+///
+/// Mais resistente a mínimos locais que uma única população grande em funções com várias
+/// raízes. Com a *feature* `rayon`, as ilhas evoluem em paralelo entre as migrações.
+pub struct IslandModel<R = StdRng> {
+    islands: Vec<Population<R>>,
+    epoch: u64,
+    migrants: usize,
+}
+
+impl<R: Rng + MaybeSync> IslandModel<R> {
+    /// Cria o modelo a partir de `islands`, que não pode ser vazio e cujos indivíduos devem
+    /// ter todos a mesma dimensão. Cada ilha mantém a sua configuração e as suas condições de
+    /// parada.
+    pub fn new(islands: Vec<Population<R>>) -> Result<Self> {
+        let Some(first) = islands.first() else {
+            return Err(RootfnError::EmptyPopulation);
+        };
+
+        let dimension = first.dimension();
+        if islands.iter().any(|island| island.dimension() != dimension) {
+            return Err(RootfnError::InvalidConfig(
+                "all islands must have the same dimension".to_string(),
+            ));
+        }
+
+        Ok(Self {
+            islands,
+            epoch: MIGRATION_EPOCH,
+            migrants: MIGRANTS,
+        })
+    }
+
+    /// Número de gerações entre duas migrações. Deve ser maior que zero.
+    pub fn epoch(mut self, generations: u64) -> Self {
+        self.epoch = generations;
+        self
+    }
+
+    /// Número de melhores indivíduos que cada ilha envia a cada migração.
+    pub fn migrants(mut self, count: usize) -> Self {
+        self.migrants = count;
+        self
+    }
+
+    pub fn islands(&self) -> &[Population<R>] {
+        &self.islands
+    }
+
+    /// Evolui as ilhas, migrando entre elas, até que alguma atinja uma condição de parada ao
+    /// fim de uma época.
+    ///
+    /// O resultado é o da ilha com o melhor `global_best`, com `evaluations` somando as
+    /// avaliações de todas as ilhas.
+    pub fn run(&mut self) -> Result<SolveResult> {
+        if self.epoch == 0 {
+            return Err(RootfnError::InvalidConfig(
+                "migration epoch must be greater than zero".to_string(),
+            ));
+        }
+
+        let start = Instant::now();
+        let evaluations: Vec<u64> = self.islands.iter().map(|i| i.evaluations()).collect();
+        for island in &mut self.islands {
+            island.start_run();
+        }
+
+        let epoch = self.epoch;
+        let run_epoch = |island: &mut Population<R>| -> Result<(bool, bool)> {
+            for _ in 0..epoch {
+                island.step()?;
+                let (stop, timed_out) = island.stop_condition(start)?;
+                if stop {
+                    return Ok((true, timed_out));
+                }
+            }
+            Ok((false, false))
+        };
+
+        let timed_out = loop {
+            #[cfg(feature = "rayon")]
+            let outcomes: Vec<(bool, bool)> = self
+                .islands
+                .par_iter_mut()
+                .map(run_epoch)
+                .collect::<Result<_>>()?;
+            #[cfg(not(feature = "rayon"))]
+            let outcomes: Vec<(bool, bool)> = self
+                .islands
+                .iter_mut()
+                .map(run_epoch)
+                .collect::<Result<_>>()?;
+
+            if outcomes.iter().any(|(stop, _)| *stop) {
+                break outcomes.iter().any(|(_, timed_out)| *timed_out);
+            }
+
+            self.migrate()?;
+        };
+
+        let best = self.best_island()?;
+        let total: u64 = self
+            .islands
+            .iter()
+            .zip(&evaluations)
+            .map(|(island, before)| island.evaluations() - before)
+            .sum();
+
+        let mut result =
+            self.islands[best].finish_run(start, evaluations[best], timed_out, None)?;
+        result.evaluations = total;
+        Ok(result)
+    }
+
+    /// Envia os melhores indivíduos de cada ilha para a seguinte.
+    fn migrate(&mut self) -> Result<()> {
+        let migrants: Vec<Vec<Genome>> = self
+            .islands
+            .iter()
+            .map(|island| {
+                island
+                    .ranked()
+                    .into_iter()
+                    .take(self.migrants)
+                    .map(|(genome, _)| genome)
+                    .collect()
+            })
+            .collect();
+
+        let n = self.islands.len();
+        for (i, migrants) in migrants.iter().enumerate() {
+            self.islands[(i + 1) % n].immigrate(migrants)?;
+        }
+        Ok(())
+    }
+
+    /// Índice da ilha com o melhor `global_best`.
+    fn best_island(&self) -> Result<usize> {
+        self.islands
+            .iter()
+            .enumerate()
+            .filter_map(|(i, island)| island.global_best_fitness().map(|fitness| (i, fitness)))
+            .min_by(|(i, a), (_, b)| self.islands[*i].compare_fitness(*a, *b))
+            .map(|(i, _)| i)
+            .ok_or(RootfnError::EmptyPopulation)
+    }
+}
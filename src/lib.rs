@@ -0,0 +1,70 @@
+//! Busca de raízes de funções por meio de algoritmos genéticos.
+//!
+//! O algoritmo em si não depende de `plotters` nem de `indicatif`: os gráficos (*feature*
+//! `plot`) e a barra de progresso (*feature* `indicatif`) são adaptadores opcionais sobre
+//! [`Population::run_with_callback`]. Com a *feature* `log`, cada
+//! geração é registrada em nível `debug` e o resultado pode ser registrado com
+//! `Population::results`.
+//!
+//! Com a *feature* `serde`, a configuração e o resultado de uma execução podem ser exportados
+//! em JSON com `Population::to_json`, e o estado de uma execução pode ser salvo com
+//! `Population::save_state` e retomado com `Population::load_state`. Com um gerador
+//! serializável, como [`Pcg64`], `Population::save_state_with_rng` também salva o estado do
+//! gerador, e a execução retomada é idêntica à que não foi interrompida.
+
+mod adaptive_size;
+mod builder;
+mod config;
+mod crossover;
+mod crowding;
+mod error;
+pub mod functions;
+mod genome;
+mod initialization;
+mod island;
+mod local_search;
+#[cfg(feature = "log")]
+mod logging;
+mod mutation;
+mod objective;
+#[cfg(feature = "plot")]
+pub mod plot;
+mod population;
+#[cfg(feature = "indicatif")]
+pub mod progress;
+mod rearrangement;
+mod registry;
+#[cfg(feature = "serde")]
+mod report;
+mod result;
+mod selection;
+pub mod solvers;
+#[cfg(feature = "serde")]
+mod state;
+mod target;
+
+pub use adaptive_size::AdaptiveSize;
+pub use builder::PopulationBuilder;
+pub use crossover::Crossover;
+pub use crowding::Crowding;
+pub use error::{Result, RootfnError};
+pub use genome::{Genome, Norm};
+pub use initialization::Initialization;
+pub use island::IslandModel;
+pub use local_search::LocalSearch;
+pub use mutation::{Annealing, MutationDecay, MutationKind};
+pub use objective::Objective;
+pub use population::{Generations, Population};
+pub use rearrangement::{
+    DiversityCollapse, Genocide, NoRearrangement, RandomPredation, Rearrangement, Rearranger,
+    Restart,
+};
+pub use registry::FunctionRegistry;
+pub use result::{GenerationStats, RootCluster, SolveResult, TrialStats};
+pub use selection::{Ranking, Selection};
+pub use target::MaybeSync;
+
+#[cfg(feature = "complex")]
+pub use num_complex::Complex;
+#[cfg(feature = "serde")]
+pub use rand_pcg::Pcg64;
@@ -0,0 +1,39 @@
+use crate::{Result, RootfnError};
+
+/// Número padrão de passos da busca local.
+const LOCAL_SEARCH_STEPS: usize = 40;
+
+/// Passo inicial padrão da busca local, relativo à largura do intervalo de busca.
+const LOCAL_SEARCH_STEP: f64 = 1e-3;
+
+/// Busca local aplicada à elite a cada geração, antes da reprodução, tornando o algoritmo
+/// memético; ver [`Population::local_search`](crate::Population::local_search).
+///
+/// `step_size` é relativo à largura do intervalo de busca atual, então acompanha o
+/// genocídio à medida que o intervalo encolhe.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct LocalSearch {
+    pub steps: usize,
+    pub step_size: f64,
+}
+
+impl Default for LocalSearch {
+    fn default() -> Self {
+        Self {
+            steps: LOCAL_SEARCH_STEPS,
+            step_size: LOCAL_SEARCH_STEP,
+        }
+    }
+}
+
+impl LocalSearch {
+    pub(crate) fn validate(&self) -> Result<()> {
+        if !(self.step_size > 0.0 && self.step_size.is_finite()) {
+            return Err(RootfnError::InvalidConfig(
+                "local search step size must be finite and positive".to_string(),
+            ));
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,64 @@
+#[cfg(feature = "serde")]
+use crate::Result;
+use crate::{target::MaybeSync, GenerationStats, Population, SolveResult};
+use log::{debug, info};
+use rand::Rng;
+
+impl<R: Rng + MaybeSync> Population<R> {
+    /// Mostra no *log* o resultado de uma execução.
+    ///
+    /// A mensagem é uma linha legível, e os mesmos dados vão como campos estruturados
+    /// (`selection`, `rearrangement`, `generations`, `fitness`, `duration_ms`, `evaluations`,
+    /// `converged`, `root_error`) para *loggers* que os suportem. Com raízes esperadas, a
+    /// mensagem também indica a mais próxima.
+    pub fn results(&self, result: &SolveResult) {
+        let mut inf = format!(
+            "({} ms) - Best by {} ({}): {:?} | Fitness: {} | Evaluations: {}",
+            result.duration.as_millis(),
+            self.selection(),
+            self.rearrangement(),
+            result.root,
+            result.fitness,
+            result.evaluations,
+        );
+        if let (Some(expected), Some(error)) = (&result.expected_root, result.root_error) {
+            inf.push_str(&format!(
+                " | Expected root: {:?} (error: {})",
+                expected, error
+            ));
+        }
+
+        info!(
+            selection:% = self.selection(),
+            rearrangement:% = self.rearrangement(),
+            generations = result.generations,
+            fitness = result.fitness,
+            duration_ms = result.duration.as_millis() as u64,
+            evaluations = result.evaluations,
+            converged = result.converged,
+            root_error:? = result.root_error;
+            "{}", inf
+        );
+    }
+
+    /// Como [`Population::results`], mas mostra o resultado em JSON, ver
+    /// [`Population::to_json`].
+    #[cfg(feature = "serde")]
+    pub fn results_json(&self, result: &SolveResult) -> Result<()> {
+        info!("{}", self.to_json(result)?);
+        Ok(())
+    }
+}
+
+/// Mostra no *log*, em nível `debug`, as estatísticas de uma geração.
+pub(crate) fn generation(stats: &GenerationStats) {
+    debug!(
+        generation = stats.generation,
+        best_fitness = stats.best_fitness,
+        average_fitness = stats.average_fitness,
+        diversity = stats.diversity;
+        "Generation {} | Best fitness: {}",
+        stats.generation,
+        stats.best_fitness
+    );
+}
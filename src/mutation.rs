@@ -0,0 +1,269 @@
+use crate::{Genome, Result, RootfnError};
+use rand::Rng;
+use rand_distr::{Distribution, Normal};
+use std::ops::Range;
+
+/// Maior amplitude de [`MutationKind::Relative`], para que `fraction * width` não estoure.
+const MAX_AMPLITUDE: f64 = f64::MAX / 2.0;
+
+/// Perturbação aplicada a cada componente de um filho após o cruzamento.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum MutationKind {
+    /// Soma um valor sorteado uniformemente em `interval`, multiplicado por `rate`.
+    Uniform { interval: Range<f64>, rate: f64 },
+    /// Soma um valor sorteado de uma normal com média zero e desvio padrão `sigma`.
+    Gaussian { sigma: f64 },
+    /// Usa a mutação uniforme com `interval` e `rate` enquanto a melhor *fitness* da geração
+    /// não fica abaixo de `switch_threshold`; a partir daí, move cada indivíduo na direção
+    /// contrária ao gradiente numérico da *fitness*.
+    ///
+    /// O passo é escolhido de modo que `step = 1.0` equivalha a um passo do método de Newton;
+    /// valores menores o amortecem.
+    Hybrid {
+        switch_threshold: f64,
+        step: f64,
+        interval: Range<f64>,
+        rate: f64,
+    },
+    /// Soma a cada componente um valor sorteado uniformemente entre `-fraction` e `fraction`
+    /// vezes a largura do intervalo de busca atual da sua dimensão. Acompanha a escala do
+    /// problema e, com o genocídio, fica mais fina à medida que o intervalo encolhe.
+    Relative { fraction: f64 },
+}
+
+impl MutationKind {
+    /// Aplica a mutação em cada componente de `genome`, com a perturbação multiplicada por
+    /// `scale`. `widths` é a largura do intervalo de busca atual de cada dimensão.
+    pub(crate) fn apply(
+        &self,
+        rng: &mut impl Rng,
+        mut genome: Genome,
+        scale: f64,
+        widths: &[f64],
+    ) -> Genome {
+        match self {
+            MutationKind::Uniform { interval, rate } => {
+                for x in genome.iter_mut() {
+                    *x += rng.gen_range(interval.clone()) * rate * scale;
+                }
+            }
+            MutationKind::Hybrid { interval, rate, .. } => {
+                return MutationKind::Uniform {
+                    interval: interval.clone(),
+                    rate: *rate,
+                }
+                .apply(rng, genome, scale, widths);
+            }
+            MutationKind::Relative { fraction } => {
+                for (x, width) in genome.iter_mut().zip(widths) {
+                    // Limitada para que `-amplitude..amplitude` tenha largura finita.
+                    let amplitude = (fraction * width).min(MAX_AMPLITUDE);
+                    if amplitude > 0.0 {
+                        *x += rng.gen_range(-amplitude..amplitude) * scale;
+                    }
+                }
+            }
+            MutationKind::Gaussian { sigma } => {
+                let normal = Normal::new(0.0, *sigma).expect("sigma is validated by the builder");
+                for x in genome.iter_mut() {
+                    *x += normal.sample(rng) * scale;
+                }
+            }
+        }
+        genome
+    }
+
+    pub(crate) fn validate(&self) -> Result<()> {
+        match self {
+            MutationKind::Uniform { interval, rate } => {
+                if !(interval.end - interval.start).is_finite() || interval.start >= interval.end {
+                    return Err(RootfnError::InvalidConfig(
+                        "mutation interval must have a finite, positive width".to_string(),
+                    ));
+                }
+                if !(*rate >= 0.0 && rate.is_finite()) {
+                    return Err(RootfnError::InvalidConfig(
+                        "mutation rate must be finite and non-negative".to_string(),
+                    ));
+                }
+            }
+            MutationKind::Gaussian { sigma } => {
+                if !(*sigma > 0.0 && sigma.is_finite()) {
+                    return Err(RootfnError::InvalidConfig(
+                        "gaussian mutation sigma must be finite and positive".to_string(),
+                    ));
+                }
+            }
+            MutationKind::Hybrid {
+                switch_threshold,
+                step,
+                interval,
+                rate,
+            } => {
+                if !(*switch_threshold >= 0.0 && switch_threshold.is_finite()) {
+                    return Err(RootfnError::InvalidConfig(
+                        "hybrid mutation threshold must be finite and non-negative".to_string(),
+                    ));
+                }
+                if !(*step > 0.0 && step.is_finite()) {
+                    return Err(RootfnError::InvalidConfig(
+                        "hybrid mutation step must be finite and positive".to_string(),
+                    ));
+                }
+                MutationKind::Uniform {
+                    interval: interval.clone(),
+                    rate: *rate,
+                }
+                .validate()?;
+            }
+            MutationKind::Relative { fraction } => {
+                if !(*fraction > 0.0 && fraction.is_finite()) {
+                    return Err(RootfnError::InvalidConfig(
+                        "relative mutation fraction must be finite and positive".to_string(),
+                    ));
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Curva usada pela mutação adaptativa para reduzir a perturbação à medida que a
+/// *fitness* se aproxima da tolerância.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum MutationDecay {
+    /// A escala cai linearmente com o progresso.
+    Linear,
+    /// A escala cai exponencialmente com o progresso.
+    Exponential,
+}
+
+/// Menor escala de mutação alcançada pela mutação adaptativa.
+const MIN_MUTATION_SCALE: f64 = 1e-3;
+
+impl MutationDecay {
+    /// Escala da mutação para um `progress` entre `0.0` (início) e `1.0` (tolerância atingida).
+    pub(crate) fn scale(&self, progress: f64) -> f64 {
+        if progress.is_nan() {
+            return 1.0;
+        }
+
+        let progress = progress.clamp(0.0, 1.0);
+        match self {
+            MutationDecay::Linear => (1.0 - progress).max(MIN_MUTATION_SCALE),
+            MutationDecay::Exponential => MIN_MUTATION_SCALE.powf(progress),
+        }
+    }
+}
+
+/// Critério de Metropolis aplicado depois da mutação, como no *simulated annealing*: um filho
+/// que a mutação deixou pior ainda é aceito com probabilidade `exp(-Δ / T)`, onde `Δ` é o
+/// aumento da *fitness*. Caso contrário, o filho segue sem a mutação.
+///
+/// A temperatura começa em `initial_temperature` e é multiplicada por `cooling` a cada
+/// geração, então pioras são aceitas com frequência no início e cada vez menos depois.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct Annealing {
+    pub initial_temperature: f64,
+    pub cooling: f64,
+}
+
+impl Annealing {
+    /// Temperatura na geração `generation`.
+    pub(crate) fn temperature(&self, generation: u64) -> f64 {
+        self.initial_temperature * self.cooling.powf(generation as f64)
+    }
+
+    /// Decide se um filho cuja *fitness* aumentou `delta` com a mutação deve ser aceito.
+    pub(crate) fn accept(&self, rng: &mut impl Rng, delta: f64, generation: u64) -> bool {
+        if delta <= 0.0 {
+            return true;
+        }
+
+        let temperature = self.temperature(generation);
+        temperature > 0.0 && rng.gen::<f64>() < (-delta / temperature).exp()
+    }
+
+    pub(crate) fn validate(&self) -> Result<()> {
+        if !(self.initial_temperature > 0.0 && self.initial_temperature.is_finite()) {
+            return Err(RootfnError::InvalidConfig(
+                "initial temperature must be finite and positive".to_string(),
+            ));
+        }
+        if !(self.cooling > 0.0 && self.cooling <= 1.0) {
+            return Err(RootfnError::InvalidConfig(
+                "cooling factor must be in (0, 1]".to_string(),
+            ));
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+    use rand::{rngs::StdRng, SeedableRng};
+
+    /// Valores negativos e positivos de magnitudes muito diferentes.
+    fn value() -> impl Strategy<Value = f64> {
+        prop_oneof![-1e250..1e250, -1e3..1e3_f64, -1e-250..1e-250]
+    }
+
+    fn kind() -> impl Strategy<Value = MutationKind> {
+        prop_oneof![
+            Just(MutationKind::Uniform {
+                interval: -10.0..10.0,
+                rate: 0.001,
+            }),
+            (1e-3..1e3_f64).prop_map(|sigma| MutationKind::Gaussian { sigma }),
+            (1e-3..1.0_f64).prop_map(|fraction| MutationKind::Relative { fraction }),
+        ]
+    }
+
+    proptest! {
+        #[test]
+        fn mutation_of_finite_genome_is_finite(
+            kind in kind(),
+            genome in prop::collection::vec(value(), 1..4),
+            scale in 0.0..1.0_f64,
+            width in 1e-6..1e250_f64,
+            seed: u64,
+        ) {
+            let widths = vec![width; genome.len()];
+            let mutated = kind.apply(&mut StdRng::seed_from_u64(seed), genome, scale, &widths);
+            prop_assert!(mutated.iter().all(|x| x.is_finite()));
+        }
+    }
+
+    #[test]
+    fn hybrid_random_phase_uses_its_interval_and_rate() {
+        let mut rng = StdRng::seed_from_u64(1);
+        let hybrid = MutationKind::Hybrid {
+            switch_threshold: 1.0,
+            step: 1.0,
+            interval: 5.0..6.0,
+            rate: 2.0,
+        };
+        for _ in 0..100 {
+            let genome = hybrid.apply(&mut rng, vec![0.0], 1.0, &[1.0]);
+            assert!((10.0..12.0).contains(&genome[0]), "{genome:?}");
+        }
+    }
+
+    #[test]
+    fn relative_amplitude_does_not_overflow() {
+        let mut rng = StdRng::seed_from_u64(1);
+        let relative = MutationKind::Relative { fraction: 1e308 };
+        assert!(relative.validate().is_ok());
+        for width in [1.0, 200.0, f64::MAX] {
+            let genome = relative.apply(&mut rng, vec![0.0], 1.0, &[width]);
+            assert!(genome[0].is_finite(), "{genome:?}");
+        }
+    }
+}
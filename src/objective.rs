@@ -0,0 +1,34 @@
+/// O que o algoritmo busca na função. O padrão é [`Objective::MinimizeAbs`].
+///
+/// Define a *fitness* de cada indivíduo, onde menor é melhor, e portanto quem é o melhor.
+/// Fora da busca de raízes não há um valor ideal conhecido, então a tolerância não encerra a
+/// execução e o resultado nunca é `converged`; use `max_generations`,
+/// `min_improvement_generations` ou `max_duration` para limitá-la.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum Objective {
+    /// Busca raízes minimizando `|f(x)|`.
+    #[default]
+    MinimizeAbs,
+    /// Busca o menor valor de `f`.
+    Minimize,
+    /// Busca o maior valor de `f`.
+    Maximize,
+}
+
+impl Objective {
+    /// *Fitness* correspondente a um valor da função.
+    pub(crate) fn fitness(self) -> fn(f64) -> f64 {
+        match self {
+            Objective::MinimizeAbs => f64::abs,
+            Objective::Minimize => |value| value,
+            Objective::Maximize => |value| -value,
+        }
+    }
+
+    /// `true` se o objetivo é encontrar raízes.
+    pub(crate) fn is_root(self) -> bool {
+        self == Objective::MinimizeAbs
+    }
+}
@@ -0,0 +1,394 @@
+use crate::{GenerationStats, Rearrangement, Result, RootfnError, Selection};
+use plotters::{
+    coord::ranged1d::{AsRangedCoord, ValueFormatter},
+    prelude::*,
+    style::full_palette::{BLUE, RED, WHITE},
+};
+use std::{
+    fs, io,
+    ops::Range,
+    path::{Path, PathBuf},
+};
+
+const PLOT_SIZE: (u32, u32) = (1920, 1080);
+
+/// Diretório padrão onde os gráficos são salvos.
+pub const PLOT_DIR: &str = "images";
+
+/// Menor valor desenhado em um eixo logarítmico.
+pub const LOG_FLOOR: f64 = 1e-16;
+
+/// Número padrão de barras de um histograma.
+pub const HISTOGRAM_BUCKETS: usize = 50;
+
+/// Desenha `data` como uma linha e salva em `{dir}/{name}`.
+///
+/// Com `log_scale`, o eixo y é logarítmico e os valores (e o início de `y_range`) menores
+/// que [`LOG_FLOOR`] são trazidos até ele. O diretório é criado caso não exista.
+pub fn plot_data(
+    dir: &Path,
+    data: &[f64],
+    name: &str,
+    caption: &str,
+    y_range: Range<f64>,
+    color: RGBColor,
+    log_scale: bool,
+) -> Result<()> {
+    fs::create_dir_all(dir).map_err(RootfnError::PlotIo)?;
+    let path = dir.join(name);
+    draw(
+        &[(caption, data, color)],
+        &path,
+        Some(caption),
+        y_range,
+        false,
+        log_scale,
+    )
+}
+
+/// Desenha várias séries, cada uma com o seu nome na legenda, no mesmo gráfico e salva em
+/// `path`. O eixo y cobre todos os valores finitos das séries.
+///
+/// `log_scale` funciona como em [`plot_data`]. O diretório de `path` é criado caso não
+/// exista.
+pub fn plot_multi(series: &[(&str, &[f64], RGBColor)], path: &Path, log_scale: bool) -> Result<()> {
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir).map_err(RootfnError::PlotIo)?;
+    }
+
+    let values = series
+        .iter()
+        .flat_map(|(_, data, _)| data.iter().copied())
+        .filter(|y| y.is_finite());
+    let (min, max) = if log_scale {
+        values
+            .map(|y| y.max(LOG_FLOOR))
+            .fold((f64::INFINITY, LOG_FLOOR), |(min, max): (f64, f64), y| {
+                (min.min(y), max.max(y))
+            })
+    } else {
+        values.fold((0.0, 0.0), |(min, max): (f64, f64), y| {
+            (min.min(y), max.max(y))
+        })
+    };
+    let y_range = if min < max { min..max } else { min..min + 1.0 };
+
+    draw(series, path, None, y_range, true, log_scale)
+}
+
+/// Desenha um histograma de `values` com `buckets` barras de mesma largura e salva em
+/// `path`, por exemplo com os indivíduos da última geração, para ver se a população convergiu
+/// para uma raiz ou se dividiu entre várias.
+///
+/// Valores não finitos são ignorados. O diretório de `path` é criado caso não exista.
+pub fn plot_histogram(values: &[f64], path: &Path, buckets: usize) -> Result<()> {
+    if buckets == 0 {
+        return Err(RootfnError::InvalidConfig(
+            "histogram must have at least one bucket".to_string(),
+        ));
+    }
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir).map_err(RootfnError::PlotIo)?;
+    }
+
+    let values: Vec<f64> = values.iter().copied().filter(|x| x.is_finite()).collect();
+    let (min, max) = values
+        .iter()
+        .fold((f64::INFINITY, f64::NEG_INFINITY), |(min, max), x| {
+            (min.min(*x), max.max(*x))
+        });
+    let (start, end) = if values.is_empty() {
+        (0.0, 1.0)
+    } else if min < max {
+        (min, max)
+    } else {
+        (min - 0.5, max + 0.5)
+    };
+
+    let width = (end - start) / buckets as f64;
+    let mut counts = vec![0u32; buckets];
+    for x in &values {
+        let bucket = (((x - start) / width) as usize).min(buckets - 1);
+        counts[bucket] += 1;
+    }
+    let y_max = counts.iter().copied().max().unwrap_or(0) + 1;
+
+    let root_area = BitMapBackend::new(path, PLOT_SIZE).into_drawing_area();
+    root_area.fill(&WHITE).map_err(plot_error)?;
+
+    let mut ctx = ChartBuilder::on(&root_area)
+        .set_label_area_size(LabelAreaPosition::Left, 100)
+        .set_label_area_size(LabelAreaPosition::Bottom, 40)
+        .set_label_area_size(LabelAreaPosition::Right, 100)
+        .build_cartesian_2d(start..end, 0..y_max)
+        .map_err(plot_error)?;
+
+    ctx.configure_mesh().draw().map_err(plot_error)?;
+
+    ctx.draw_series(counts.iter().enumerate().map(|(i, count)| {
+        let x0 = start + width * i as f64;
+        Rectangle::new([(x0, 0), (x0 + width, *count)], BLUE.filled())
+    }))
+    .map_err(plot_error)?;
+
+    root_area.present().map_err(plot_error)?;
+
+    Ok(())
+}
+
+/// Desenha `series` em um único gráfico, com legenda se `legend` for `true`.
+fn draw(
+    series: &[(&str, &[f64], RGBColor)],
+    path: &Path,
+    caption: Option<&str>,
+    y_range: Range<f64>,
+    legend: bool,
+    log_scale: bool,
+) -> Result<()> {
+    if !log_scale {
+        return draw_on(series, path, caption, y_range, legend);
+    }
+
+    let clamped: Vec<Vec<f64>> = series
+        .iter()
+        .map(|(_, data, _)| data.iter().map(|y| y.max(LOG_FLOOR)).collect())
+        .collect();
+    let series: Vec<(&str, &[f64], RGBColor)> = series
+        .iter()
+        .zip(&clamped)
+        .map(|((name, _, color), data)| (*name, data.as_slice(), *color))
+        .collect();
+
+    let start = if y_range.start.is_finite() {
+        y_range.start.max(LOG_FLOOR)
+    } else {
+        LOG_FLOOR
+    };
+    let end = y_range.end.max(start * 10.0);
+    draw_on(&series, path, caption, (start..end).log_scale(), legend)
+}
+
+/// Desenha `series` com o eixo y dado por `y_coord`, linear ou logarítmico.
+fn draw_on<Y>(
+    series: &[(&str, &[f64], RGBColor)],
+    path: &Path,
+    caption: Option<&str>,
+    y_coord: Y,
+    legend: bool,
+) -> Result<()>
+where
+    Y: AsRangedCoord<Value = f64>,
+    Y::CoordDescType: ValueFormatter<f64>,
+{
+    let root_area = BitMapBackend::new(path, PLOT_SIZE).into_drawing_area();
+    root_area.fill(&WHITE).map_err(plot_error)?;
+
+    let len = series
+        .iter()
+        .map(|(_, data, _)| data.len())
+        .max()
+        .unwrap_or(0);
+
+    let mut builder = ChartBuilder::on(&root_area);
+    builder
+        .set_label_area_size(LabelAreaPosition::Left, 100)
+        .set_label_area_size(LabelAreaPosition::Bottom, 40)
+        .set_label_area_size(LabelAreaPosition::Right, 100);
+    if let Some(caption) = caption {
+        builder.caption(caption, ("sans-serif", 40));
+    }
+    let mut ctx = builder
+        .build_cartesian_2d(0..len, y_coord)
+        .map_err(plot_error)?;
+
+    ctx.configure_mesh().draw().map_err(plot_error)?;
+
+    for (name, data, color) in series {
+        let color = *color;
+        ctx.draw_series(LineSeries::new(
+            (0..).zip(data.iter()).map(|(x, y)| (x, *y)),
+            color,
+        ))
+        .map_err(plot_error)?
+        .label(*name)
+        .legend(move |(x, y)| PathElement::new(vec![(x, y), (x + 20, y)], color));
+    }
+
+    if legend {
+        ctx.configure_series_labels()
+            .background_style(WHITE.mix(0.8))
+            .border_style(BLACK)
+            .draw()
+            .map_err(plot_error)?;
+    }
+
+    root_area.present().map_err(plot_error)?;
+
+    Ok(())
+}
+
+/// Converte os erros do `plotters` em [`RootfnError::PlotIo`].
+fn plot_error(e: impl std::error::Error) -> RootfnError {
+    RootfnError::PlotIo(io::Error::other(e.to_string()))
+}
+
+/// Guarda a evolução de uma execução para salvar os gráficos do melhor *fitness* e da média
+/// de cada geração.
+///
+/// Feito para ser usado como *callback* de
+/// [`Population::run_with_callback`](crate::Population::run_with_callback).
+#[derive(Debug, Clone)]
+pub struct HistoryPlot {
+    best: Vec<f64>,
+    average: Vec<f64>,
+    log_scale: bool,
+    dir: PathBuf,
+    buckets: usize,
+}
+
+impl Default for HistoryPlot {
+    fn default() -> Self {
+        Self {
+            best: Vec::new(),
+            average: Vec::new(),
+            log_scale: false,
+            dir: PathBuf::from(PLOT_DIR),
+            buckets: HISTOGRAM_BUCKETS,
+        }
+    }
+}
+
+impl HistoryPlot {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Diretório onde os gráficos são salvos, criado caso não exista. O padrão é
+    /// [`PLOT_DIR`].
+    pub fn dir(mut self, dir: impl Into<PathBuf>) -> Self {
+        self.dir = dir.into();
+        self
+    }
+
+    /// Usa um eixo y logarítmico no gráfico do melhor *fitness*. A média dos valores da
+    /// função pode ser negativa e continua em escala linear.
+    pub fn log_scale(mut self, log_scale: bool) -> Self {
+        self.log_scale = log_scale;
+        self
+    }
+
+    /// Número de barras do histograma de [`HistoryPlot::save_histogram`]. O padrão é
+    /// [`HISTOGRAM_BUCKETS`].
+    pub fn buckets(mut self, buckets: usize) -> Self {
+        self.buckets = buckets;
+        self
+    }
+
+    /// Registra uma geração.
+    pub fn record(&mut self, stats: &GenerationStats) {
+        self.best.push(stats.best_fitness);
+        self.average.push(stats.average);
+    }
+
+    /// Salva os gráficos em `{dir}/best_{selection}_{rearrangement}.png` e
+    /// `{dir}/aveg_{selection}_{rearrangement}.png`.
+    pub fn save(&self, selection: Selection, rearrangement: Rearrangement) -> Result<()> {
+        let y_max_best = self.best.iter().copied().fold(0.0, f64::max);
+        let y_min_best = if self.log_scale {
+            self.best.iter().copied().fold(f64::INFINITY, f64::min)
+        } else {
+            0.0
+        };
+        let y_max_aveg = self.average.iter().copied().fold(0.0, f64::max);
+
+        let (suffix, label) = plot_names(selection, rearrangement);
+        let name = format!("best{}.png", suffix);
+        let caption = format!("Best by {}", label);
+
+        plot_data(
+            &self.dir,
+            &self.best,
+            name.as_str(),
+            caption.as_str(),
+            y_min_best..y_max_best,
+            BLUE,
+            self.log_scale,
+        )?;
+
+        plot_data(
+            &self.dir,
+            &self.average,
+            name.replace("best", "aveg").as_str(),
+            caption.replace("Best", "Aveg").as_str(),
+            0.0..y_max_aveg,
+            RED,
+            false,
+        )?;
+
+        Ok(())
+    }
+
+    /// Salva o histograma de `values`, como os indivíduos da última geração, em
+    /// `{dir}/hist_{selection}_{rearrangement}.png`; ver [`plot_histogram`].
+    pub fn save_histogram(
+        &self,
+        values: &[f64],
+        selection: Selection,
+        rearrangement: Rearrangement,
+    ) -> Result<()> {
+        let (suffix, _) = plot_names(selection, rearrangement);
+        plot_histogram(
+            values,
+            &self.dir.join(format!("hist{}.png", suffix)),
+            self.buckets,
+        )
+    }
+}
+
+/// Sufixo dos arquivos e nome da estratégia nos títulos dos gráficos de uma execução.
+fn plot_names(selection: Selection, rearrangement: Rearrangement) -> (String, String) {
+    let mut name = String::new();
+    let mut caption = String::new();
+
+    match selection {
+        Selection::Elitism => {
+            name.push_str("_elitism");
+            caption.push_str("Elitism");
+        }
+
+        Selection::Tournament { size } => {
+            name.push_str(&format!("_{}", selection));
+            caption.push_str(&format!("Tournament ({})", size))
+        }
+
+        Selection::Roulette => {
+            name.push_str("_roulette");
+            caption.push_str("Roulette")
+        }
+
+        Selection::Sus => {
+            name.push_str("_sus");
+            caption.push_str("SUS")
+        }
+
+        Selection::Rank { ranking } => {
+            name.push_str(&format!("_rank_{}", ranking));
+            caption.push_str(&format!("Rank ({})", ranking))
+        }
+
+        Selection::DifferentialEvolution { f, cr } => {
+            name.push_str("_differential_evolution");
+            caption.push_str(&format!("Differential Evolution (F = {}, CR = {})", f, cr))
+        }
+    }
+
+    match rearrangement {
+        Rearrangement::None => (),
+        Rearrangement::Genocide => name.push_str("_genocide"),
+        Rearrangement::RandomPredation => name.push_str("_random_predation"),
+        Rearrangement::Restart => name.push_str("_restart"),
+        Rearrangement::Custom => name.push_str("_custom"),
+    }
+
+    (name, caption)
+}
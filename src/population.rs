@@ -0,0 +1,2391 @@
+#[cfg(feature = "plot")]
+use crate::plot::HistoryPlot;
+#[cfg(feature = "indicatif")]
+use crate::progress::Progress;
+#[cfg(feature = "serde")]
+use crate::state::State;
+use crate::{
+    config::{Config, RELATIVE_DELTA_FLOOR},
+    crossover::{fitter_weight, Crossover, WEIGHT_EPSILON},
+    genome::{distance, norm},
+    initialization::individual,
+    solvers,
+    target::{BatchFn, FitnessFn, MaybeSync, PenaltyFn, Target},
+    AdaptiveSize, DiversityCollapse, GenerationStats, Genome, MutationKind, NoRearrangement,
+    PopulationBuilder, Ranking, Rearrangement, Rearranger, Result, RootfnError, Selection,
+    SolveResult, TrialStats,
+};
+use rand::{
+    distributions::{Distribution, WeightedIndex},
+    rngs::StdRng,
+    seq::{index::sample, SliceRandom},
+    Rng,
+};
+#[cfg(feature = "rayon")]
+use rayon::prelude::*;
+use std::{
+    fs::File,
+    io::{BufWriter, Write},
+    ops::Range,
+    path::Path,
+    sync::atomic::{AtomicU64, Ordering},
+    time::Instant,
+};
+
+/// Número de passos do método de Newton aplicados com `polish`.
+const POLISH_ITERATIONS: usize = 20;
+
+/// Fator pelo qual a largura do intervalo de busca é multiplicada a cada genocídio.
+const GENOCIDE_SHRINK: Range<f64> = 0.5..1.0;
+
+/// Largura mínima do intervalo do genocídio, relativa à magnitude dos seus limites.
+const MIN_GENOCIDE_WIDTH: f64 = 1e-12;
+
+/// No modo discreto, gerações seguidas sem melhora do melhor inteiro até encerrar a execução.
+const DISCRETE_PATIENCE: u64 = 100;
+
+/// Maior fator aplicado à mutação por [`Population::on_plateau`].
+const MAX_PLATEAU_BOOST: f64 = 1024.0;
+
+/// Gerações seguidas com melhora do `global_best` até [`AdaptiveSize`] diminuir a população.
+const IMPROVEMENT_STREAK: u64 = 5;
+
+/// Razão entre os pesos de duas posições consecutivas no *ranking* exponencial.
+const EXPONENTIAL_RANK_BASE: f64 = 0.95;
+
+/// População do algoritmo genético.
+///
+/// `R` é o gerador de números aleatórios usado em todas as etapas; o padrão é [`StdRng`].
+/// Use [`PopulationBuilder::rng`] para trocá-lo, por exemplo por um gerador mais rápido.
+pub struct Population<R = StdRng> {
+    target: Target,
+    /// Converte o valor da função na *fitness*.
+    fitness: FitnessFn,
+    /// Penalidade somada à *fitness*, com peso `penalty_weight`.
+    penalty: Option<PenaltyFn>,
+    selection: Selection,
+    rearrangement: Rearrangement,
+    rearranger: Box<dyn Rearranger<R>>,
+    /// Intervalo de busca de cada dimensão.
+    range: Vec<Range<f64>>,
+    ind: Vec<Genome>,
+    /// Valor da função e *fitness* de cada indivíduo, na ordem de `ind`. `None` indica que
+    /// o indivíduo mudou desde a última avaliação.
+    cache: Vec<Option<(f64, f64)>>,
+    /// *Fitness* de cada indivíduo com a penalidade de `crowding`, calculada antes da
+    /// seleção. Vazia sem a penalidade ou depois que algum indivíduo muda.
+    crowded: Vec<f64>,
+    generation: u64,
+    global_best: Option<Genome>,
+    /// *Fitness* do `global_best`, guardada para não reavaliá-lo a cada geração.
+    global_fitness: Option<f64>,
+    best: Option<Genome>,
+    last_best: Option<Genome>,
+    /// *Fitness* do `best` da geração atual.
+    best_fitness: Option<f64>,
+    /// *Fitness* do primeiro `best`, usada como referência pela mutação adaptativa.
+    initial_fitness: Option<f64>,
+    /// Gerações seguidas em que o melhor indivíduo se moveu menos que `best_delta`.
+    stagnation: u64,
+    /// Gerações seguidas estagnadas, para `plateau_length`.
+    plateau: u64,
+    /// Gerações seguidas sem melhora do `global_best`.
+    unchanged: u64,
+    /// Gerações seguidas com melhora do `global_best`, para `adaptive_size`.
+    improving: u64,
+    /// A diversidade colapsou com [`DiversityCollapse::Stop`].
+    collapsed: bool,
+    /// `plateau`, `unchanged` e `improving` vieram de um estado carregado e continuam na
+    /// próxima execução em vez de recomeçar.
+    resumed: bool,
+    /// Raízes já encontradas por [`Population::find_all_roots`], penalizadas na *fitness*.
+    suppressed: Vec<Genome>,
+    /// Fator aplicado à mutação enquanto a população está em um platô.
+    mutation_boost: f64,
+    /// Estatísticas de cada geração, guardadas apenas com `record_history`.
+    history: Vec<GenerationStats>,
+    /// Indivíduos de cada geração, guardados apenas com `record_snapshots`.
+    snapshots: Vec<Vec<Genome>>,
+    /// Número de avaliações da função, zerado por [`Population::reset_evaluations`].
+    evaluations: AtomicU64,
+    config: Config,
+    /// Gerador de números aleatórios usado em todas as etapas do algoritmo.
+    rng: R,
+}
+
+impl Population {
+    pub fn new(
+        f: impl Fn(f64) -> f64 + MaybeSync + 'static,
+        selection: Selection,
+        rearrangement: Rearrangement,
+    ) -> Self {
+        PopulationBuilder::new(f)
+            .selection(selection)
+            .rearrangement(rearrangement)
+            .build()
+            .expect("default configuration is valid")
+    }
+
+    /// Cria uma população com [`Selection::Elitism`], sem reorganização e com os
+    /// hiperparâmetros padrão.
+    pub fn elitism(f: impl Fn(f64) -> f64 + MaybeSync + 'static) -> Self {
+        Self::new(f, Selection::Elitism, Rearrangement::None)
+    }
+
+    /// Cria uma população com o [`Selection::Tournament`] padrão, sem reorganização e com os
+    /// hiperparâmetros padrão, equivalente a `PopulationBuilder::new(f).build()`.
+    pub fn tournament(f: impl Fn(f64) -> f64 + MaybeSync + 'static) -> Self {
+        Self::new(f, Selection::default(), Rearrangement::None)
+    }
+
+    /// Cria uma população reprodutível: a mesma `seed` gera sempre o mesmo resultado.
+    pub fn with_seed(
+        f: impl Fn(f64) -> f64 + MaybeSync + 'static,
+        seed: u64,
+        selection: Selection,
+        rearrangement: Rearrangement,
+    ) -> Self {
+        PopulationBuilder::new(f)
+            .seed(seed)
+            .selection(selection)
+            .rearrangement(rearrangement)
+            .build()
+            .expect("default configuration is valid")
+    }
+
+    /// Cria uma população cuja primeira geração contém `values`, completada com indivíduos
+    /// sorteados no intervalo inicial. Útil quando já se tem uma estimativa da raiz.
+    pub fn with_initial(
+        f: impl Fn(f64) -> f64 + MaybeSync + 'static,
+        values: Vec<f64>,
+        selection: Selection,
+        rearrangement: Rearrangement,
+    ) -> Self {
+        PopulationBuilder::new(f)
+            .initial_population(values.into_iter().map(|x| vec![x]).collect())
+            .selection(selection)
+            .rearrangement(rearrangement)
+            .build()
+            .expect("default configuration is valid")
+    }
+}
+
+impl<R: Rng + MaybeSync> Population<R> {
+    #[allow(clippy::too_many_arguments)]
+    pub(crate) fn from_parts(
+        target: Target,
+        fitness: FitnessFn,
+        penalty: Option<PenaltyFn>,
+        selection: Selection,
+        rearrangement: Rearrangement,
+        rearranger: Box<dyn Rearranger<R>>,
+        config: Config,
+        rng: R,
+    ) -> Self {
+        let mut population = Self {
+            target,
+            fitness,
+            penalty,
+            selection,
+            rearrangement,
+            rearranger,
+            ind: Vec::new(),
+            cache: Vec::new(),
+            crowded: Vec::new(),
+            range: Vec::new(),
+            generation: 0,
+            global_best: None,
+            global_fitness: None,
+            best: None,
+            last_best: None,
+            best_fitness: None,
+            initial_fitness: None,
+            stagnation: 0,
+            plateau: 0,
+            unchanged: 0,
+            improving: 0,
+            collapsed: false,
+            resumed: false,
+            suppressed: Vec::new(),
+            mutation_boost: 1.0,
+            history: Vec::new(),
+            snapshots: Vec::new(),
+            evaluations: AtomicU64::new(0),
+            config,
+            rng,
+        };
+        population.reset();
+        population
+    }
+
+    /// Sorteia uma nova população, a partir dos indivíduos iniciais se houver, e descarta todo
+    /// o progresso das execuções anteriores.
+    pub fn reset(&mut self) {
+        let intervals = self.initial_intervals();
+        let size = self.config.population_size;
+        let mut ind: Vec<Genome> = self
+            .config
+            .initial_population
+            .iter()
+            .take(size)
+            .map(|genome| self.constrain(genome.clone()))
+            .collect();
+        let missing = size.saturating_sub(ind.len());
+        ind.extend(self.new_individuals(missing, &intervals));
+        self.ind = ind;
+        self.cache = vec![None; self.ind.len()];
+        self.crowded.clear();
+        self.range = intervals;
+        self.generation = 0;
+        self.global_best = None;
+        self.global_fitness = None;
+        self.best = None;
+        self.last_best = None;
+        self.best_fitness = None;
+        self.initial_fitness = None;
+        self.stagnation = 0;
+        self.plateau = 0;
+        self.unchanged = 0;
+        self.improving = 0;
+        self.collapsed = false;
+        self.resumed = false;
+        self.mutation_boost = 1.0;
+        self.history.clear();
+        self.snapshots.clear();
+    }
+
+    /// Estado atual da execução, para [`Population::save_state`].
+    #[cfg(feature = "serde")]
+    pub(crate) fn state(&self) -> State {
+        State {
+            individuals: self.ind.clone(),
+            generation: self.generation,
+            range: self.range.clone(),
+            global_best: self.global_best.clone(),
+            best: self.best.clone(),
+            last_best: self.last_best.clone(),
+            best_fitness: self.best_fitness,
+            initial_fitness: self.initial_fitness,
+            stagnation: self.stagnation,
+            plateau: self.plateau,
+            unchanged: self.unchanged,
+            improving: self.improving,
+            mutation_boost: self.mutation_boost,
+            suppressed: self.suppressed.clone(),
+        }
+    }
+
+    /// Gerador de números aleatórios, para [`Population::save_state_with_rng`].
+    #[cfg(feature = "serde")]
+    pub(crate) fn rng(&self) -> &R {
+        &self.rng
+    }
+
+    /// Gerador de números aleatórios, para [`Population::load_state_with_rng`].
+    #[cfg(feature = "serde")]
+    pub(crate) fn rng_mut(&mut self) -> &mut R {
+        &mut self.rng
+    }
+
+    /// Substitui o estado da execução por `state`, para [`Population::load_state`].
+    #[cfg(feature = "serde")]
+    pub(crate) fn restore(&mut self, state: State) -> Result<()> {
+        if state.individuals.is_empty() {
+            return Err(RootfnError::EmptyPopulation);
+        }
+
+        let dimension = self.dimension();
+        let genomes = state
+            .individuals
+            .iter()
+            .chain(&state.global_best)
+            .chain(&state.best)
+            .chain(&state.last_best)
+            .chain(&state.suppressed);
+        for genome in genomes {
+            if genome.len() != dimension {
+                return Err(RootfnError::InvalidConfig(format!(
+                    "saved state has an individual of dimension {}, expected {}",
+                    genome.len(),
+                    dimension
+                )));
+            }
+        }
+        if state.range.len() != dimension {
+            return Err(RootfnError::InvalidConfig(format!(
+                "saved state has {} search intervals, expected {}",
+                state.range.len(),
+                dimension
+            )));
+        }
+        if state
+            .range
+            .iter()
+            .any(|range| !(range.end - range.start).is_finite() || range.start >= range.end)
+        {
+            return Err(RootfnError::InvalidConfig(
+                "saved search interval must have a finite, positive width".to_string(),
+            ));
+        }
+
+        self.cache = vec![None; state.individuals.len()];
+        self.crowded.clear();
+        self.ind = state.individuals;
+        self.generation = state.generation;
+        self.range = state.range;
+        self.global_fitness = state.global_best.as_ref().map(|best| self.fitness(best));
+        self.global_best = state.global_best;
+        self.best = state.best;
+        self.last_best = state.last_best;
+        self.best_fitness = state.best_fitness;
+        self.initial_fitness = state.initial_fitness;
+        self.stagnation = state.stagnation;
+        self.plateau = state.plateau;
+        self.unchanged = state.unchanged;
+        self.improving = state.improving;
+        self.collapsed = false;
+        self.resumed = true;
+        self.mutation_boost = state.mutation_boost;
+        self.suppressed = state.suppressed;
+        self.history.clear();
+        self.snapshots.clear();
+        Ok(())
+    }
+
+    /// Retorna o valor do indivíduo presente no `index`.
+    pub fn value(&self, index: usize) -> Result<&Genome> {
+        self.ind.get(index).ok_or(RootfnError::IndexOutOfBounds {
+            index,
+            len: self.ind.len(),
+        })
+    }
+
+    /// Altera o valor do indivíduo presente no `index` para `v`.
+    pub fn set(&mut self, index: usize, v: Genome) -> Result<()> {
+        let len = self.ind.len();
+        let i = self
+            .ind
+            .get_mut(index)
+            .ok_or(RootfnError::IndexOutOfBounds { index, len })?;
+        if *i != v {
+            *i = v;
+            self.cache[index] = None;
+            self.crowded.clear();
+        }
+        Ok(())
+    }
+
+    /// Melhor indivíduo da última geração avaliada por [`Population::step`], guardado ao
+    /// avaliá-la. Não percorre a população, então é barato chamá-lo a cada geração, por
+    /// exemplo no *callback* de [`Population::run_with_callback`].
+    ///
+    /// É `None` antes da primeira geração e logo depois de um genocídio ou reinício, que
+    /// descartam a geração avaliada. Para o melhor indivíduo da população atual, que a
+    /// seleção já pode ter alterado, use [`Population::best_index`].
+    pub fn best(&self) -> Option<&Genome> {
+        self.best.as_ref()
+    }
+
+    /// *Fitness* do [`Population::best`], sem avaliar a função de novo.
+    pub fn best_fitness(&self) -> Option<f64> {
+        self.best_fitness
+    }
+
+    /// Retorna o índice do melhor indivíduo da atual geração.
+    ///
+    /// Indivíduos com *fitness* `NaN` ou infinita são ignorados. Se nenhum tiver *fitness*
+    /// finita, retorna [`RootfnError::NoFiniteFitness`].
+    pub fn best_index(&self) -> Result<usize> {
+        self.best_k_indices(1)?
+            .first()
+            .copied()
+            .ok_or(RootfnError::NoFiniteFitness)
+    }
+
+    /// Índices dos até `k` melhores indivíduos, do melhor para o pior.
+    ///
+    /// Como em [`Population::best_index`], indivíduos sem *fitness* finita são ignorados,
+    /// então o resultado pode ter menos de `k` elementos. Em caso de empate na *fitness*,
+    /// vem antes o indivíduo de menor norma e, depois, o de menor índice.
+    pub fn best_k_indices(&self, k: usize) -> Result<Vec<usize>> {
+        if self.ind.is_empty() {
+            return Err(RootfnError::EmptyPopulation);
+        }
+
+        let fitness = self.fitnesses();
+        let mut order: Vec<usize> = (0..fitness.len())
+            .filter(|i| fitness[*i].is_finite())
+            .collect();
+        order.sort_by(|a, b| self.compare_individuals(&fitness, *a, *b));
+        order.truncate(k);
+
+        Ok(order)
+    }
+
+    /// Todos os indivíduos com a sua *fitness*, do melhor para o pior.
+    ///
+    /// Indivíduos sem *fitness* finita ficam no fim, e empates seguem a mesma ordem de
+    /// [`Population::best_k_indices`].
+    pub fn ranked(&self) -> Vec<(Genome, f64)> {
+        let fitness = self.fitnesses();
+        let mut order: Vec<usize> = (0..fitness.len()).collect();
+        order.sort_by(|a, b| self.compare_individuals(&fitness, *a, *b));
+        order
+            .into_iter()
+            .map(|i| (self.ind[i].clone(), fitness[i]))
+            .collect()
+    }
+
+    /// Ordem entre os indivíduos `a` e `b`, dada a *fitness* de cada indivíduo da população:
+    /// `Less` quando `a` é melhor.
+    ///
+    /// Menor *fitness* vence. Em caso de empate, vence o indivíduo de menor norma e, se ainda
+    /// empatarem, o de menor índice.
+    fn compare_individuals(&self, fitness: &[f64], a: usize, b: usize) -> std::cmp::Ordering {
+        self.compare_fitness(fitness[a], fitness[b])
+            .then_with(|| norm(&self.ind[a]).total_cmp(&norm(&self.ind[b])))
+            .then(a.cmp(&b))
+    }
+
+    /// Ordem entre duas *fitness*: `Less` quando `a` é melhor.
+    ///
+    /// Todas as comparações de *fitness* da população, e entre as ilhas do
+    /// [`IslandModel`](crate::IslandModel), passam por aqui ou por
+    /// [`Population::is_better`]. A [`Objective`](crate::Objective) já converte o valor da
+    /// função em uma *fitness* em que menor é melhor, inclusive na maximização.
+    pub(crate) fn compare_fitness(&self, a: f64, b: f64) -> std::cmp::Ordering {
+        a.total_cmp(&b)
+    }
+
+    /// `true` se a *fitness* `a` é estritamente melhor que `b`.
+    fn is_better(&self, a: f64, b: f64) -> bool {
+        self.compare_fitness(a, b).is_lt()
+    }
+
+    /// Valor da função e *fitness* de todos os indivíduos, na ordem da população.
+    ///
+    /// Usa o cache quando possível; os demais são avaliados, em paralelo com a *feature*
+    /// `rayon`.
+    ///
+    /// Com [`PopulationBuilder::batch`], os indivíduos que mudaram são avaliados em uma única
+    /// chamada da função.
+    fn scores(&self) -> Vec<(f64, f64)> {
+        if let Target::Batch(f) = &self.target {
+            return self.batch_scores(f);
+        }
+
+        let score = |(x, cached): (&Genome, &Option<(f64, f64)>)| {
+            cached.unwrap_or_else(|| self.evaluate(x))
+        };
+
+        #[cfg(feature = "rayon")]
+        let scores = self.ind.par_iter().zip(&self.cache).map(score).collect();
+        #[cfg(not(feature = "rayon"))]
+        let scores = self.ind.iter().zip(&self.cache).map(score).collect();
+        scores
+    }
+
+    /// [`Population::scores`] com uma função em lote: uma chamada para todos os indivíduos
+    /// fora do cache. Valores que faltarem no retorno contam como `NaN`.
+    fn batch_scores(&self, f: &BatchFn) -> Vec<(f64, f64)> {
+        let inputs: Vec<f64> = self
+            .ind
+            .iter()
+            .zip(&self.cache)
+            .filter(|(_, cached)| cached.is_none())
+            .map(|(x, _)| x[0])
+            .collect();
+        if inputs.is_empty() {
+            return self.cache.iter().flatten().copied().collect();
+        }
+
+        self.evaluations
+            .fetch_add(inputs.len() as u64, Ordering::Relaxed);
+        let mut values = f(&inputs).into_iter();
+        self.ind
+            .iter()
+            .zip(&self.cache)
+            .map(|(x, cached)| {
+                cached.unwrap_or_else(|| {
+                    let value = values.next().unwrap_or(f64::NAN);
+                    (value, self.score(x, value))
+                })
+            })
+            .collect()
+    }
+
+    /// Avalia os indivíduos que mudaram desde a última chamada e guarda o resultado.
+    fn update_cache(&mut self) {
+        self.cache = self.scores().into_iter().map(Some).collect();
+    }
+
+    /// *Fitness* de todos os indivíduos, na ordem da população.
+    fn fitnesses(&self) -> Vec<f64> {
+        self.scores()
+            .into_iter()
+            .map(|(_, fitness)| fitness)
+            .collect()
+    }
+
+    /// Valor da função em cada indivíduo, na ordem da população.
+    fn function_values(&self) -> Vec<f64> {
+        self.scores().into_iter().map(|(value, _)| value).collect()
+    }
+
+    /// *Fitness* usada pela seleção: com `crowding`, a calculada por
+    /// [`Population::update_crowding`]; sem ela, a própria *fitness*.
+    fn selection_fitnesses(&self) -> Vec<f64> {
+        if self.crowded.len() == self.ind.len() {
+            self.crowded.clone()
+        } else {
+            self.fitnesses()
+        }
+    }
+
+    /// *Fitness* usada pela seleção para o indivíduo no `index`, como em
+    /// [`Population::selection_fitnesses`].
+    fn selection_fitness_at(&self, index: usize) -> Result<f64> {
+        match self.crowded.get(index) {
+            Some(fitness) if self.crowded.len() == self.ind.len() => Ok(*fitness),
+            _ => self.fitness_at(index),
+        }
+    }
+
+    /// Aplica a penalidade de `crowding`, se configurada, à *fitness* de cada indivíduo,
+    /// contando os vizinhos a menos de `radius` dele.
+    fn update_crowding(&mut self) {
+        let Some(crowding) = self.config.crowding else {
+            return;
+        };
+
+        let fitness = self.fitnesses();
+        let mut order: Vec<usize> = (0..fitness.len()).collect();
+        order.sort_by(|a, b| self.compare_fitness(fitness[*a], fitness[*b]));
+        let mut ranks = vec![0; fitness.len()];
+        for (rank, index) in order.into_iter().enumerate() {
+            ranks[index] = rank;
+        }
+
+        self.crowded = self
+            .ind
+            .iter()
+            .zip(ranks)
+            .enumerate()
+            .map(|(i, (genome, rank))| {
+                let neighbors = self
+                    .ind
+                    .iter()
+                    .enumerate()
+                    .filter(|(j, other)| *j != i && distance(genome, other) < crowding.radius)
+                    .count();
+                crowding.penalize(rank, neighbors)
+            })
+            .collect();
+    }
+
+    /// *Fitness* do indivíduo no `index`, do cache se possível.
+    fn fitness_at(&self, index: usize) -> Result<f64> {
+        match self.cache.get(index) {
+            Some(Some((_, fitness))) => Ok(*fitness),
+            _ => Ok(self.fitness(self.value(index)?)),
+        }
+    }
+
+    /// Avalia a função em `x`, contando a avaliação. `None` se uma função de
+    /// [`PopulationBuilder::fallible`] falhar.
+    fn try_eval(&self, x: &[f64]) -> Option<f64> {
+        self.evaluations.fetch_add(1, Ordering::Relaxed);
+        self.target.eval(x, self.config.norm)
+    }
+
+    /// [`Population::try_eval`], com `NaN` quando a função falha.
+    fn eval(&self, x: &[f64]) -> f64 {
+        self.try_eval(x).unwrap_or(f64::NAN)
+    }
+
+    /// Número de vezes que a função foi avaliada desde a criação da população ou desde a
+    /// última chamada a [`Population::reset_evaluations`].
+    pub fn evaluations(&self) -> u64 {
+        self.evaluations.load(Ordering::Relaxed)
+    }
+
+    /// Zera o contador de avaliações da função.
+    pub fn reset_evaluations(&mut self) {
+        *self.evaluations.get_mut() = 0;
+    }
+
+    /// Estratégia de seleção usada a cada geração.
+    pub fn selection(&self) -> Selection {
+        self.selection
+    }
+
+    /// Estratégia de reorganização usada a cada geração.
+    pub fn rearrangement(&self) -> Rearrangement {
+        self.rearrangement
+    }
+
+    /// Número de gerações seguidas em que o melhor indivíduo se moveu menos que `best_delta`.
+    ///
+    /// Volta a zero quando o melhor indivíduo se move ou depois de um genocídio.
+    pub fn stagnation(&self) -> u64 {
+        self.stagnation
+    }
+
+    /// Dimensão de cada indivíduo.
+    pub fn dimension(&self) -> usize {
+        self.target.dimension()
+    }
+
+    /// Intervalo inicial de cada dimensão.
+    fn initial_intervals(&self) -> Vec<Range<f64>> {
+        self.config.initial_intervals(self.dimension())
+    }
+
+    /// Verificação rápida, antes de uma execução longa, de que a função tem alguma raiz no
+    /// intervalo inicial: avalia a função em `samples + 1` pontos igualmente espaçados e
+    /// retorna `true` se ela mudar de sinal entre dois pontos vizinhos ou ficar abaixo de
+    /// `fitness_tolerance`, em módulo, em algum deles.
+    ///
+    /// `false` não prova que não há raiz: raízes de multiplicidade par entre dois pontos
+    /// passam despercebidas, como em [`solvers::brackets`]. Sistemas e funções complexas não
+    /// mudam de sinal, então para eles retorna sempre `true`.
+    pub fn contains_root(&self, samples: usize) -> bool {
+        if !self.target.is_scalar() {
+            return true;
+        }
+
+        let samples = samples.max(1);
+        let interval = &self.initial_intervals()[0];
+        let width = (interval.end - interval.start) / samples as f64;
+        let values: Vec<f64> = (0..=samples)
+            .map(|i| {
+                if i == samples {
+                    interval.end
+                } else {
+                    interval.start + width * i as f64
+                }
+            })
+            .map(|x| self.eval(&[x]))
+            .collect();
+
+        values
+            .iter()
+            .any(|value| value.abs() < self.config.fitness_tolerance)
+            || values.windows(2).any(|w| w[0] * w[1] < 0.0)
+    }
+
+    /// *Fitness* de `x`: por padrão, o módulo do valor da função, somado à penalidade, se
+    /// houver. Menor é melhor.
+    ///
+    /// Se a função não puder ser avaliada em `x` (`NaN` ou infinito), a *fitness* é
+    /// `f64::INFINITY`, a pior possível.
+    pub fn fitness(&self, x: &[f64]) -> f64 {
+        self.evaluate(x).1
+    }
+
+    /// Valor da função em `x` e a *fitness* correspondente, com uma única avaliação.
+    ///
+    /// Uma falha da função é a pior *fitness* possível, qualquer que seja a
+    /// [`PopulationBuilder::fitness`].
+    fn evaluate(&self, x: &[f64]) -> (f64, f64) {
+        match self.try_eval(x) {
+            Some(value) => (value, self.score(x, value)),
+            None => (f64::NAN, f64::INFINITY),
+        }
+    }
+
+    /// *Fitness* de `x`, dado o valor da função nele.
+    fn score(&self, x: &[f64], value: f64) -> f64 {
+        let penalty = self
+            .penalty
+            .as_ref()
+            .map_or(0.0, |penalty| self.config.penalty_weight * penalty(x));
+        let fitness = ((self.fitness)(value) + penalty) * self.deflation(x);
+        if fitness.is_finite() {
+            fitness
+        } else {
+            f64::INFINITY
+        }
+    }
+
+    /// Fator que aumenta a *fitness* perto das raízes já encontradas, para que o algoritmo
+    /// não volte a convergir para elas.
+    ///
+    /// Longe de qualquer raiz suprimida o fator tende a `1.0`.
+    fn deflation(&self, x: &[f64]) -> f64 {
+        self.suppressed
+            .iter()
+            .map(|root| 1.0 + distance(x, root).powi(-2))
+            .product()
+    }
+
+    /// Média dos valores da função nos indivíduos. Os valores `NaN` ou infinitos são
+    /// ignorados; zero se não sobrar nenhum.
+    pub fn average_value(&self) -> f64 {
+        mean(&self.function_values())
+    }
+
+    /// Média da *fitness* dos indivíduos, ignorando as infinitas. Zero se não sobrar nenhuma.
+    pub fn average_fitness(&self) -> f64 {
+        mean(&self.fitnesses())
+    }
+
+    /// Dispersão da população: raiz da soma das variâncias de cada dimensão.
+    ///
+    /// Para uma dimensão é o desvio padrão dos indivíduos.
+    pub fn diversity(&self) -> f64 {
+        let n = self.ind.len();
+        if n == 0 {
+            return 0.0;
+        }
+
+        let variance: f64 = (0..self.dimension())
+            .map(|d| {
+                let mean = self.ind.iter().map(|i| i[d]).sum::<f64>() / n as f64;
+                self.ind.iter().map(|i| (i[d] - mean).powi(2)).sum::<f64>() / n as f64
+            })
+            .sum();
+
+        variance.sqrt()
+    }
+
+    /// Busca até `max_roots` raízes distintas executando o algoritmo várias vezes.
+    ///
+    /// A cada raiz encontrada, a vizinhança dela é penalizada na *fitness* das próximas
+    /// execuções. A busca para na primeira execução que não converge. Raízes a menos de
+    /// `root_epsilon` de outra já encontrada são descartadas.
+    pub fn find_all_roots(&mut self, max_roots: usize) -> Result<Vec<Genome>> {
+        let mut roots: Vec<Genome> = Vec::new();
+
+        for _ in 0..max_roots {
+            self.reset();
+            let result = self.run(false)?;
+            if !result.converged {
+                break;
+            }
+
+            if roots
+                .iter()
+                .all(|root| distance(root, &result.root) >= self.config.root_epsilon)
+            {
+                roots.push(result.root.clone());
+            }
+            self.suppressed.push(result.root);
+        }
+
+        self.suppressed.clear();
+        Ok(roots)
+    }
+
+    fn elitism_generation(&mut self) -> Result<()> {
+        let best_index = self.best_index()?;
+        let best = self.value(best_index)?.clone();
+        let elite = self.best_k_indices(self.config.elite_count)?;
+
+        for i in 0..self.ind.len() {
+            if !elite.contains(&i) {
+                let v = self.value(i)?.clone();
+                let v = self.recombine(&v, &best);
+                let v = self.mutate_child(v);
+                self.set(i, v)?;
+            }
+        }
+        self.generation += 1;
+        Ok(())
+    }
+
+    fn tournament_generation(&mut self, size: usize) -> Result<()> {
+        self.breed(|pop| pop.tournament_pick(size))
+    }
+
+    /// Seleção por roleta: a chance de um indivíduo ser escolhido como pai é
+    /// inversamente proporcional à sua *fitness*.
+    fn roulette_generation(&mut self) -> Result<()> {
+        self.weighted(self.selection_weights())
+    }
+
+    /// Seleção por *ranking*: a chance de um indivíduo ser escolhido como pai depende
+    /// apenas da sua posição quando ordenado pela *fitness*.
+    fn rank_generation(&mut self, ranking: Ranking) -> Result<()> {
+        self.weighted(self.rank_weights(ranking))
+    }
+
+    /// Amostragem universal estocástica: usa os mesmos pesos da roleta, mas sorteia todos os
+    /// pais de uma vez com ponteiros igualmente espaçados, reduzindo a variância.
+    fn sus_generation(&mut self) -> Result<()> {
+        let weights = self.selection_weights();
+        // Dois pais para cada indivíduo fora da elite, a mesma elite que `breed` mantém.
+        let elite = self.best_k_indices(self.config.elite_count)?;
+        let count = 2 * (self.ind.len() - elite.len());
+
+        let mut parents = Vec::with_capacity(count);
+        if count > 0 {
+            let step = weights.iter().sum::<f64>() / count as f64;
+            let mut pointer = self.rng.gen_range(0.0..step);
+            let mut cumulative = 0.0;
+
+            for (i, weight) in weights.iter().enumerate() {
+                cumulative += weight;
+                while pointer < cumulative && parents.len() < count {
+                    parents.push(i);
+                    pointer += step;
+                }
+            }
+
+            // Erros de arredondamento podem deixar o último ponteiro de fora.
+            while parents.len() < count {
+                parents.push(weights.len() - 1);
+            }
+        }
+
+        parents.shuffle(&mut self.rng);
+
+        self.breed(|pop| {
+            let index = parents.pop().expect("one parent is drawn per pointer");
+            pop.value(index).cloned()
+        })
+    }
+
+    /// Gera a próxima geração por evolução diferencial, ver
+    /// [`Selection::DifferentialEvolution`]. Os vetores de teste são montados a partir da
+    /// geração atual e substituem o indivíduo apenas se a *fitness* não piorar.
+    fn differential_evolution_generation(&mut self, f: f64, cr: f64) -> Result<()> {
+        let n = self.ind.len();
+        if n < 4 {
+            return Err(RootfnError::InvalidConfig(
+                "differential evolution requires at least four individuals".to_string(),
+            ));
+        }
+
+        let dimension = self.target.dimension();
+        let mut trials = Vec::with_capacity(n);
+        for i in 0..n {
+            let others: Vec<usize> = sample(&mut self.rng, n - 1, 3)
+                .into_iter()
+                .map(|k| if k >= i { k + 1 } else { k })
+                .collect();
+            let (a, b, c) = (
+                &self.ind[others[0]],
+                &self.ind[others[1]],
+                &self.ind[others[2]],
+            );
+            let target = &self.ind[i];
+
+            let rng = &mut self.rng;
+            let forced = rng.gen_range(0..dimension);
+            let trial: Genome = (0..dimension)
+                .map(|j| {
+                    if j == forced || rng.gen_bool(cr) {
+                        a[j] + f * (b[j] - c[j])
+                    } else {
+                        target[j]
+                    }
+                })
+                .collect();
+            trials.push(self.constrain(trial));
+        }
+
+        for (i, trial) in trials.into_iter().enumerate() {
+            let score = self.evaluate(&trial);
+            if !self.is_better(self.fitness_at(i)?, score.1) {
+                self.set(i, trial)?;
+                self.cache[i] = Some(score);
+            }
+        }
+
+        self.generation += 1;
+        Ok(())
+    }
+
+    /// Gera a próxima geração sorteando os pais de acordo com `weights`.
+    fn weighted(&mut self, weights: Vec<f64>) -> Result<()> {
+        let wheel = WeightedIndex::new(weights).map_err(|_| RootfnError::EmptyPopulation)?;
+        self.breed(|pop| {
+            let index = wheel.sample(&mut pop.rng);
+            pop.value(index).cloned()
+        })
+    }
+
+    /// Gera a próxima geração cruzando pais escolhidos por `parent`.
+    ///
+    /// Os `elite_count` melhores indivíduos são mantidos.
+    fn breed(&mut self, mut parent: impl FnMut(&mut Self) -> Result<Genome>) -> Result<()> {
+        let elite = self.best_k_indices(self.config.elite_count)?;
+
+        let mut children = Vec::<Genome>::with_capacity(self.ind.len());
+
+        for i in 0..self.ind.len() {
+            if elite.contains(&i) {
+                children.push(self.value(i)?.clone());
+            } else {
+                let dad = parent(self)?;
+                let mom = parent(self)?;
+
+                let child = self.recombine(&dad, &mom);
+                let child = self.mutate_child(child);
+                children.push(child);
+            }
+        }
+
+        for (i, child) in children.into_iter().enumerate() {
+            self.set(i, child)?;
+        }
+
+        self.generation += 1;
+        Ok(())
+    }
+
+    /// Pesos de seleção a partir da posição de cada indivíduo no *ranking*.
+    ///
+    /// O melhor indivíduo recebe o maior peso.
+    fn rank_weights(&self, ranking: Ranking) -> Vec<f64> {
+        let fitness = self.selection_fitnesses();
+        let n = fitness.len();
+
+        let mut order: Vec<usize> = (0..n).collect();
+        order.sort_by(|a, b| self.compare_fitness(fitness[*a], fitness[*b]));
+
+        let mut weights = vec![0.0; n];
+        for (rank, index) in order.into_iter().enumerate() {
+            weights[index] = match ranking {
+                Ranking::Linear => (n - rank) as f64,
+                Ranking::Exponential => EXPONENTIAL_RANK_BASE.powi(rank as i32),
+            };
+        }
+
+        weights
+    }
+
+    /// Pesos de seleção inversamente proporcionais à *fitness* de cada indivíduo.
+    ///
+    /// Se todos tiverem a mesma *fitness*, os pesos são uniformes. *Fitness* negativas, como
+    /// as de [`Objective::Maximize`](crate::Objective::Maximize), são deslocadas para que a
+    /// menor seja zero.
+    fn selection_weights(&self) -> Vec<f64> {
+        let fitness = self.selection_fitnesses();
+
+        let uniform = vec![1.0; fitness.len()];
+        if fitness.windows(2).all(|w| w[0] == w[1]) {
+            return uniform;
+        }
+
+        let offset = fitness.iter().copied().fold(0.0, f64::min);
+        let weights: Vec<f64> = fitness
+            .iter()
+            .map(|f| 1.0 / (f - offset + WEIGHT_EPSILON))
+            .map(|w| if w.is_finite() { w } else { 0.0 })
+            .collect();
+
+        let total: f64 = weights.iter().sum();
+        if total > 0.0 && total.is_finite() {
+            weights
+        } else {
+            uniform
+        }
+    }
+
+    /// Cruza `a` e `b` com o operador de *crossover* configurado.
+    ///
+    /// Com [`Crossover::WeightedArithmetic`], a *fitness* dos dois pais é avaliada para
+    /// calcular os pesos.
+    pub fn recombine(&mut self, a: &[f64], b: &[f64]) -> Genome {
+        let weight = match self.config.crossover {
+            Crossover::WeightedArithmetic => fitter_weight(self.fitness(a), self.fitness(b)),
+            _ => 0.5,
+        };
+        self.config.crossover.apply(&mut self.rng, a, b, weight)
+    }
+
+    /// Aplica a mutação configurada em `genome`.
+    ///
+    /// No modo discreto, cada perturbação diferente de zero é arredondada para longe do zero,
+    /// para que a mutação mova o indivíduo em pelo menos uma unidade.
+    fn mutate(&mut self, genome: Genome) -> Genome {
+        let scale = self.current_mutation_scale();
+        let widths = self.widths();
+        let original = genome.clone();
+        let mut genome = match self.config.mutation {
+            MutationKind::Hybrid {
+                switch_threshold,
+                step,
+                ..
+            } if self
+                .best_fitness
+                .is_some_and(|best| best < switch_threshold) =>
+            {
+                self.gradient_step(&genome, step).unwrap_or_else(|| {
+                    self.config
+                        .mutation
+                        .apply(&mut self.rng, genome, scale, &widths)
+                })
+            }
+            _ => self
+                .config
+                .mutation
+                .apply(&mut self.rng, genome, scale, &widths),
+        };
+
+        if self.config.discrete {
+            for (x, before) in genome.iter_mut().zip(&original) {
+                let delta = *x - before;
+                *x = before + delta.signum() * delta.abs().ceil();
+            }
+        }
+
+        self.constrain(genome)
+    }
+
+    /// Aplica a mutação em `child` e, com `annealing`, decide pelo critério de Metropolis se
+    /// fica com o resultado ou com o filho sem a mutação.
+    fn mutate_child(&mut self, child: Genome) -> Genome {
+        let Some(annealing) = self.config.annealing else {
+            return self.mutate(child);
+        };
+
+        let child = self.constrain(child);
+        let mutated = self.mutate(child.clone());
+        let before = self.fitness(&child);
+        let delta = self.fitness(&mutated) - before;
+        // Sem isso, um filho e uma mutação ambos inviáveis dariam `delta = INF - INF = NaN`,
+        // que o critério de Metropolis sempre rejeita.
+        if before.is_infinite() || annealing.accept(&mut self.rng, delta, self.generation) {
+            mutated
+        } else {
+            child
+        }
+    }
+
+    /// Passo de descida de [`MutationKind::Hybrid`] a partir de `genome`, ou `None` se o
+    /// gradiente numérico for nulo ou não finito.
+    ///
+    /// Desce pelo quadrado da *fitness*, que, ao contrário de `|f|`, é suave perto da raiz.
+    fn gradient_step(&self, genome: &[f64], step: f64) -> Option<Genome> {
+        if !self.config.objective.is_root() {
+            return None;
+        }
+
+        let squared = |point: &[f64]| self.fitness(point).powi(2);
+        let value = squared(genome);
+        let gradient: Vec<f64> = (0..genome.len())
+            .map(|i| {
+                let f = |x: f64| {
+                    let mut point = genome.to_vec();
+                    point[i] = x;
+                    squared(&point)
+                };
+                solvers::derivative(&f, genome[i], solvers::derivative_step(genome[i]))
+            })
+            .collect();
+
+        let squared_norm: f64 = gradient.iter().map(|g| g * g).sum();
+        if !(squared_norm > 0.0 && squared_norm.is_finite() && value.is_finite()) {
+            return None;
+        }
+
+        let factor = 2.0 * step * value / squared_norm;
+        Some(
+            genome
+                .iter()
+                .zip(&gradient)
+                .map(|(x, g)| x - factor * g)
+                .collect(),
+        )
+    }
+
+    /// Sorteia um novo indivíduo com cada componente no seu intervalo de `intervals`,
+    /// respeitando o domínio.
+    fn new_individual(&mut self, intervals: &[Range<f64>]) -> Genome {
+        let genome = individual(&mut self.rng, intervals);
+        self.constrain(genome)
+    }
+
+    /// Sorteia `count` novos indivíduos de uma vez, espalhados em `intervals` conforme a
+    /// `initialization` configurada e respeitando o domínio.
+    fn new_individuals(&mut self, count: usize, intervals: &[Range<f64>]) -> Vec<Genome> {
+        self.config
+            .initialization
+            .sample(&mut self.rng, count, intervals)
+            .into_iter()
+            .map(|genome| self.constrain(genome))
+            .collect()
+    }
+
+    /// Largura do intervalo de busca atual de cada dimensão.
+    fn widths(&self) -> Vec<f64> {
+        self.range
+            .iter()
+            .map(|range| range.end - range.start)
+            .collect()
+    }
+
+    /// Traz cada componente de `genome` para dentro do domínio, se houver um, e o arredonda
+    /// para o inteiro mais próximo no modo discreto.
+    fn constrain(&self, mut genome: Genome) -> Genome {
+        if self.config.discrete {
+            for x in genome.iter_mut() {
+                *x = x.round();
+            }
+        }
+
+        for (i, x) in genome.iter_mut().enumerate() {
+            let Some(domain) = self.config.domain(i) else {
+                continue;
+            };
+            let (start, end) = if self.config.discrete {
+                (domain.start.ceil(), domain.end.floor())
+            } else {
+                (domain.start, domain.end)
+            };
+            *x = x.clamp(start, end);
+        }
+        genome
+    }
+
+    /// Fator que multiplica a perturbação da mutação na geração atual.
+    ///
+    /// Sem mutação adaptativa e fora de um platô é sempre `1.0`. Com ela, o progresso é
+    /// medido em ordens de grandeza entre a *fitness* inicial e a tolerância. Cada platô
+    /// detectado por [`Population::on_plateau`] dobra o fator.
+    pub fn current_mutation_scale(&self) -> f64 {
+        self.adaptive_scale() * self.mutation_boost
+    }
+
+    /// Parte de [`Population::current_mutation_scale`] que vem da mutação adaptativa.
+    fn adaptive_scale(&self) -> f64 {
+        let Some(decay) = self.config.adaptive_mutation else {
+            return 1.0;
+        };
+
+        let (Some(initial), Some(current)) = (self.initial_fitness, self.best_fitness) else {
+            return 1.0;
+        };
+
+        let tolerance = self.config.fitness_tolerance;
+        if initial <= tolerance {
+            return decay.scale(1.0);
+        }
+
+        let progress =
+            (initial.ln() - current.max(tolerance).ln()) / (initial.ln() - tolerance.ln());
+        decay.scale(progress)
+    }
+
+    /// Refina o indivíduo no `index` com até `steps` passos de subida de encosta: em cada
+    /// passo, tenta somar e subtrair `step_size` de cada componente e fica com a primeira
+    /// mudança que melhora a *fitness*. Quando nenhuma melhora, o passo cai pela metade.
+    pub fn local_search(&mut self, index: usize, steps: usize, step_size: f64) -> Result<()> {
+        let mut current = self.value(index)?.clone();
+        let mut score = match self.cache.get(index) {
+            Some(Some(score)) => *score,
+            _ => self.evaluate(&current),
+        };
+        let mut step_size = step_size;
+
+        for _ in 0..steps {
+            let mut improved = false;
+            for j in 0..current.len() {
+                for delta in [step_size, -step_size] {
+                    let mut candidate = current.clone();
+                    candidate[j] += delta;
+                    let candidate = self.constrain(candidate);
+                    let candidate_score = self.evaluate(&candidate);
+                    if self.is_better(candidate_score.1, score.1) {
+                        (current, score) = (candidate, candidate_score);
+                        improved = true;
+                        break;
+                    }
+                }
+            }
+
+            if !improved {
+                step_size /= 2.0;
+            }
+        }
+
+        self.set(index, current)?;
+        self.cache[index] = Some(score);
+        Ok(())
+    }
+
+    /// Reação a `plateau_length` gerações seguidas sem o melhor indivíduo se mover mais que
+    /// `best_delta`, independente do [`Rearrangement`] escolhido: dobra a escala da mutação,
+    /// até um limite, e aplica a predação aleatória.
+    ///
+    /// A escala volta ao normal assim que o melhor indivíduo se move de novo.
+    pub fn on_plateau(&mut self) -> Result<()> {
+        self.mutation_boost = (self.mutation_boost * 2.0).min(MAX_PLATEAU_BOOST);
+        self.random_predation()
+    }
+
+    /// Sorteia `size` indivíduos e retorna o melhor deles. Em caso de empate, vence o
+    /// sorteado por último.
+    fn tournament_pick(&mut self, size: usize) -> Result<Genome> {
+        let mut winner = self.random_index();
+        for _ in 1..size {
+            let candidate = self.random_index();
+            let (candidate_fitness, winner_fitness) = (
+                self.selection_fitness_at(candidate)?,
+                self.selection_fitness_at(winner)?,
+            );
+            if !self.is_better(winner_fitness, candidate_fitness) {
+                winner = candidate;
+            }
+        }
+
+        self.value(winner).cloned()
+    }
+
+    /// Retorna o índice de um indivíduo sorteado.
+    fn random_index(&mut self) -> usize {
+        self.rng.gen_range(0..self.ind.len())
+    }
+
+    /// Substitui toda a população por indivíduos sorteados em torno do `global_best`.
+    ///
+    /// O intervalo de busca de cada dimensão é recentrado no melhor indivíduo já encontrado
+    /// e a sua largura é multiplicada por um fator aleatório entre `0.5` e `1.0`, o mesmo
+    /// para todas, então cada genocídio explora uma região menor perto do que já se sabe ser
+    /// bom. Com `genocide_keeps_best`, o `global_best` fica no lugar do primeiro indivíduo.
+    pub fn genocide(&mut self) -> Result<()> {
+        let m = self.rng.gen_range(GENOCIDE_SHRINK);
+
+        let range: Vec<Range<f64>> = self
+            .range
+            .iter()
+            .enumerate()
+            .map(|(i, range)| {
+                let half_width = (range.end - range.start).abs() * m / 2.0;
+                let center = match &self.global_best {
+                    Some(best) => best[i],
+                    None => (range.start + range.end) / 2.0,
+                };
+
+                // Normaliza os limites, caso algum cálculo acima os inverta, e evita um
+                // intervalo vazio quando a largura se anula.
+                let start = (center - half_width).min(center + half_width);
+                let end = (center - half_width).max(center + half_width);
+                let min_width = MIN_GENOCIDE_WIDTH * start.abs().max(end.abs()).max(1.0);
+                if end - start >= min_width {
+                    start..end
+                } else {
+                    let center = (start + end) / 2.0;
+                    (center - min_width / 2.0)..(center + min_width / 2.0)
+                }
+            })
+            .collect();
+        self.range = range.clone();
+        self.best = None;
+        self.last_best = None;
+        self.best_fitness = None;
+        self.stagnation = 0;
+        let individuals = self.new_individuals(self.ind.len(), &range);
+        for (i, v) in individuals.into_iter().enumerate() {
+            self.set(i, v)?;
+        }
+        if self.config.genocide_keeps_best {
+            if let Some(best) = self.global_best.clone() {
+                self.set(0, best)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Sorteia toda a população de novo no intervalo inicial, que volta a ser o intervalo de
+    /// busca, e coloca o `global_best` no lugar de um dos indivíduos para não perder o
+    /// progresso.
+    ///
+    /// Diferente do [`Population::genocide`], não concentra a busca perto do melhor
+    /// indivíduo, então é uma saída mais ampla de uma estagnação local.
+    pub fn restart(&mut self) -> Result<()> {
+        let range = self.initial_intervals();
+        self.range = range.clone();
+        self.best = None;
+        self.last_best = None;
+        self.best_fitness = None;
+        self.stagnation = 0;
+        let individuals = self.new_individuals(self.ind.len(), &range);
+        for (i, v) in individuals.into_iter().enumerate() {
+            self.set(i, v)?;
+        }
+        if let Some(best) = self.global_best.clone() {
+            self.set(0, best)?;
+        }
+        Ok(())
+    }
+
+    /// Substitui os `predation_count` piores indivíduos por novos, sorteados no intervalo de
+    /// busca atual, que o genocídio pode ter reduzido.
+    pub fn random_predation(&mut self) -> Result<()> {
+        if self.ind.is_empty() {
+            return Err(RootfnError::EmptyPopulation);
+        }
+
+        let interval = self.range.clone();
+        for i in self.worst_k_indices(self.config.predation_count) {
+            let v = self.new_individual(&interval);
+            self.set(i, v)?;
+        }
+        Ok(())
+    }
+
+    /// Índices dos até `k` piores indivíduos, em ordem indefinida.
+    fn worst_k_indices(&mut self, k: usize) -> Vec<usize> {
+        self.update_cache();
+        let fitness = self.fitnesses();
+        let k = k.min(fitness.len());
+
+        // Do pior para o melhor; `NaN` e infinito têm *fitness* infinita e são sempre os
+        // piores. Empates ficam com o menor índice.
+        let mut order: Vec<usize> = (0..fitness.len()).collect();
+        let worst_first = |a: &usize, b: &usize| {
+            self.compare_fitness(fitness[*b], fitness[*a])
+                .then(a.cmp(b))
+        };
+        if k > 0 && k < order.len() {
+            order.select_nth_unstable_by(k - 1, worst_first);
+        }
+        order.truncate(k);
+        order
+    }
+
+    /// Muda o número de indivíduos para `size`: descarta os piores ou acrescenta novos,
+    /// sorteados no intervalo de busca atual.
+    ///
+    /// Retorna [`RootfnError::InvalidConfig`] se `size` for zero ou pequeno demais para a
+    /// seleção, como na [`Selection::DifferentialEvolution`].
+    pub fn resize(&mut self, size: usize) -> Result<()> {
+        if size == 0 {
+            return Err(RootfnError::InvalidConfig(
+                "population size must be greater than zero".to_string(),
+            ));
+        }
+        self.selection.validate(size)?;
+
+        let len = self.ind.len();
+        if size < len {
+            let mut worst = self.worst_k_indices(len - size);
+            worst.sort_unstable_by(|a, b| b.cmp(a));
+            for i in worst {
+                self.ind.remove(i);
+                self.cache.remove(i);
+            }
+        } else {
+            let range = self.range.clone();
+            for _ in len..size {
+                let v = self.new_individual(&range);
+                self.ind.push(v);
+                self.cache.push(None);
+            }
+        }
+        self.crowded.clear();
+        Ok(())
+    }
+
+    /// Aplica a política de [`AdaptiveSize`] depois de uma geração.
+    fn adapt_size(&mut self, adaptive_size: AdaptiveSize) -> Result<()> {
+        let len = self.ind.len();
+        if self.diversity() < self.config.best_delta && len < adaptive_size.max {
+            self.resize((len + adaptive_size.step).min(adaptive_size.max))?;
+        } else if self.improving >= IMPROVEMENT_STREAK && len > adaptive_size.min {
+            self.resize(
+                len.saturating_sub(adaptive_size.step)
+                    .max(adaptive_size.min),
+            )?;
+            self.improving = 0;
+        }
+        Ok(())
+    }
+
+    /// Substitui os piores indivíduos por `migrants`, vindos de outra população.
+    pub(crate) fn immigrate(&mut self, migrants: &[Genome]) -> Result<()> {
+        for (i, migrant) in self
+            .worst_k_indices(migrants.len())
+            .into_iter()
+            .zip(migrants)
+        {
+            self.set(i, self.constrain(migrant.clone()))?;
+        }
+        Ok(())
+    }
+
+    /// Sorteia de novo no intervalo de busca atual cada indivíduo a menos de `best_delta` de
+    /// algum indivíduo anterior a ele, mantendo sempre a primeira cópia.
+    fn deduplicate(&mut self) -> Result<()> {
+        let range = self.range.clone();
+        for i in 1..self.ind.len() {
+            let duplicate = self.ind[..i]
+                .iter()
+                .any(|other| distance(other, &self.ind[i]) < self.config.best_delta);
+            if duplicate {
+                let v = self.new_individual(&range);
+                self.set(i, v)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Aplica o [`Rearranger`] configurado, que precisa de acesso mutável à população.
+    fn apply_rearranger(&mut self) -> Result<()> {
+        let mut rearranger = std::mem::replace(&mut self.rearranger, Box::new(NoRearrangement));
+        let result = rearranger.apply(self);
+        self.rearranger = rearranger;
+        result
+    }
+
+    /// Executa o algoritmo até convergir ou atingir o número máximo de gerações.
+    ///
+    /// `plot` só tem efeito com a *feature* `plot` habilitada; em funções de uma variável,
+    /// também salva o histograma da última geração. Com a *feature* `indicatif`,
+    /// mostra o avanço das gerações em uma barra de progresso. Retorna
+    /// [`RootfnError::EmptyPopulation`] se não houver indivíduos e
+    /// [`RootfnError::NoFiniteFitness`] se a função não puder ser avaliada em nenhum deles.
+    #[cfg_attr(not(feature = "plot"), allow(unused_variables))]
+    pub fn run(&mut self, plot: bool) -> Result<SolveResult> {
+        #[cfg(feature = "plot")]
+        let mut history = plot.then(|| HistoryPlot::new().dir(&self.config.plot_dir));
+        #[cfg(feature = "indicatif")]
+        let mut progress = Progress::generations(self.config.max_generations);
+
+        let result = self.run_with_callback(|stats| {
+            #[cfg(feature = "plot")]
+            if let Some(history) = &mut history {
+                history.record(stats);
+            }
+            #[cfg(feature = "indicatif")]
+            progress.record(stats);
+        })?;
+
+        #[cfg(feature = "indicatif")]
+        progress.finish();
+        #[cfg(feature = "plot")]
+        if let Some(history) = history {
+            history.save(self.selection, self.rearrangement)?;
+            if self.dimension() == 1 {
+                let values: Vec<f64> = self.ind.iter().map(|genome| genome[0]).collect();
+                history.save_histogram(&values, self.selection, self.rearrangement)?;
+            }
+        }
+        Ok(result)
+    }
+
+    /// Executa o algoritmo `n` vezes, sorteando uma nova população antes de cada execução, e
+    /// resume os resultados.
+    ///
+    /// As raízes das execuções que convergiram são agrupadas em [`TrialStats::clusters`],
+    /// com a média e a dispersão de cada agrupamento.
+    ///
+    /// Todas as execuções usam o mesmo gerador de números aleatórios, então uma população
+    /// criada com `seed` produz sempre as mesmas estatísticas.
+    pub fn run_trials(&mut self, n: usize) -> Result<TrialStats> {
+        if n == 0 {
+            return Err(RootfnError::InvalidConfig(
+                "number of trials must be greater than zero".to_string(),
+            ));
+        }
+
+        let mut results = Vec::with_capacity(n);
+        for _ in 0..n {
+            self.reset();
+            results.push(self.run_with_callback(|_| ())?);
+        }
+
+        Ok(TrialStats::from_results(
+            &results,
+            self.config.cluster_radius,
+        ))
+    }
+
+    /// Avança uma geração: avalia a população, atualiza o melhor indivíduo, aplica a
+    /// seleção e, se for o caso, a reorganização, e retorna as estatísticas da geração
+    /// avaliada.
+    ///
+    /// Não verifica as condições de parada; é o que [`Population::run`] chama a cada
+    /// iteração, e permite conduzir o algoritmo de fora, uma geração por vez.
+    pub fn step(&mut self) -> Result<GenerationStats> {
+        let start = self.config.time_generations.then(Instant::now);
+        self.update_cache();
+        if let Some(local_search) = self.config.local_search {
+            let width = self.widths().into_iter().fold(0.0, f64::max);
+            let step_size = local_search.step_size * width;
+            for index in self.best_k_indices(self.config.elite_count)? {
+                self.local_search(index, local_search.steps, step_size)?;
+            }
+        }
+        let best_index = self.best_index()?;
+        let best = self.value(best_index)?.clone();
+        let fitness = self.fitness_at(best_index)?;
+        self.last_best = self.best.replace(best.clone());
+        self.best_fitness = Some(fitness);
+        self.initial_fitness.get_or_insert(fitness);
+        self.update_global_best();
+
+        let mut stats = GenerationStats {
+            generation: self.generation,
+            best,
+            best_fitness: fitness,
+            global_best: self.global_best.clone().unwrap_or_default(),
+            average: self.average_value(),
+            average_fitness: self.average_fitness(),
+            diversity: self.diversity(),
+            duration: None,
+        };
+        #[cfg(feature = "log")]
+        crate::logging::generation(&stats);
+        if self.config.record_snapshots {
+            self.snapshots.push(self.ind.clone());
+        }
+
+        self.update_crowding();
+        match self.selection {
+            Selection::Elitism => self.elitism_generation()?,
+            Selection::Tournament { size } => self.tournament_generation(size)?,
+            Selection::Roulette => self.roulette_generation()?,
+            Selection::Sus => self.sus_generation()?,
+            Selection::Rank { ranking } => self.rank_generation(ranking)?,
+            Selection::DifferentialEvolution { f, cr } => {
+                self.differential_evolution_generation(f, cr)?
+            }
+        }
+
+        if self.config.deduplicate {
+            self.deduplicate()?;
+        }
+
+        let stagnated = match (&self.best, &self.last_best) {
+            (Some(best), Some(last_best)) => Some(self.moved_less_than_delta(best, last_best)),
+            _ => None,
+        };
+
+        match stagnated {
+            Some(true) => self.stagnation += 1,
+            Some(false) => self.stagnation = 0,
+            None => (),
+        }
+
+        if self.rearranger.should_apply(self) {
+            self.apply_rearranger()?;
+        }
+
+        if let Some(length) = self.config.plateau_length {
+            match stagnated {
+                Some(true) => {
+                    self.plateau += 1;
+                    if self.plateau >= length {
+                        self.on_plateau()?;
+                        self.plateau = 0;
+                    }
+                }
+                Some(false) => {
+                    self.plateau = 0;
+                    self.mutation_boost = 1.0;
+                }
+                None => (),
+            }
+        }
+
+        if self.config.diversity_collapse != DiversityCollapse::Continue
+            && self.diversity() < self.config.best_delta
+        {
+            match self.config.diversity_collapse {
+                DiversityCollapse::Continue => (),
+                DiversityCollapse::Rearrange => match self.rearrangement {
+                    Rearrangement::RandomPredation
+                    | Rearrangement::Restart
+                    | Rearrangement::Custom => self.apply_rearranger()?,
+                    _ => self.genocide()?,
+                },
+                DiversityCollapse::Stop => self.collapsed = true,
+            }
+        }
+
+        if let Some(adaptive_size) = self.config.adaptive_size {
+            self.adapt_size(adaptive_size)?;
+        }
+
+        stats.duration = start.map(|start| start.elapsed());
+        if self.config.record_history {
+            self.history.push(stats.clone());
+        }
+
+        Ok(stats)
+    }
+
+    /// Substitui o `global_best` pelo `best` da geração atual se ele tiver *fitness*
+    /// estritamente menor, e atualiza os contadores de gerações com e sem melhora. Na
+    /// primeira geração o `best` vira o `global_best` sem mexer nos contadores.
+    ///
+    /// É chamado uma única vez por geração, em [`Population::step`], logo depois de o `best`
+    /// ser calculado. Fora o `polish` no fim da execução, que também nunca piora o resultado,
+    /// nenhum outro caminho altera o `global_best`, nem o genocídio e o reinício, que só
+    /// descartam o `best`; então a *fitness* do `global_best` nunca piora.
+    fn update_global_best(&mut self) {
+        let (Some(best), Some(fitness)) = (&self.best, self.best_fitness) else {
+            return;
+        };
+
+        match self.global_fitness {
+            Some(global_fitness) => {
+                if self.is_better(fitness, global_fitness) {
+                    self.global_best = Some(best.clone());
+                    self.global_fitness = Some(fitness);
+                    self.unchanged = 0;
+                    self.improving += 1;
+                } else {
+                    self.unchanged += 1;
+                    self.improving = 0;
+                }
+            }
+            None => {
+                self.global_best = Some(best.clone());
+                self.global_fitness = Some(fitness);
+            }
+        }
+    }
+
+    /// Iterador sobre as gerações: cada `next()` avança uma geração com
+    /// [`Population::step`] e retorna as suas estatísticas.
+    ///
+    /// Para depois da geração em que alguma condição de parada configurada é atingida, como
+    /// em [`Population::run`], ou depois do primeiro erro. Permite expressar critérios de
+    /// parada próprios com os adaptadores de [`Iterator`], por exemplo
+    /// `pop.generations().take(500)`. Não aplica o `polish` nem monta um [`SolveResult`].
+    pub fn generations(&mut self) -> Generations<'_, R> {
+        self.start_run();
+
+        Generations {
+            population: self,
+            start: Instant::now(),
+            timed_out: false,
+            done: false,
+        }
+    }
+
+    /// *Fitness* do `global_best`, se já houver um.
+    pub(crate) fn global_best_fitness(&self) -> Option<f64> {
+        self.global_fitness
+    }
+
+    /// `true` se `fitness` atinge a tolerância. Só vale na busca de raízes.
+    fn converged(&self, fitness: f64) -> bool {
+        self.config.objective.is_root() && fitness < self.config.fitness_tolerance
+    }
+
+    /// Zera os contadores usados pelas condições de parada antes de uma execução, exceto
+    /// logo depois de carregar um estado, quando eles continuam de onde pararam.
+    pub(crate) fn start_run(&mut self) {
+        if !std::mem::take(&mut self.resumed) {
+            self.plateau = 0;
+            self.unchanged = 0;
+            self.improving = 0;
+        }
+        self.collapsed = false;
+    }
+
+    /// Verifica as condições de parada depois de uma geração. Retorna `(stop, timed_out)`.
+    pub(crate) fn stop_condition(&self, start: Instant) -> Result<(bool, bool)> {
+        if self.collapsed {
+            return Ok((true, false));
+        }
+
+        let fitness = self
+            .global_best_fitness()
+            .ok_or(RootfnError::EmptyPopulation)?;
+        let converged = self.converged(fitness);
+        let timed_out = !converged
+            && self
+                .config
+                .max_duration
+                .is_some_and(|max| start.elapsed() >= max);
+
+        let stop = converged
+            || self.generation > self.config.max_generations
+            || timed_out
+            || (self.config.discrete && self.unchanged >= DISCRETE_PATIENCE)
+            || self.stalled();
+
+        Ok((stop, timed_out))
+    }
+
+    /// `true` se o `global_best` não melhora há `min_improvement_generations` gerações.
+    fn stalled(&self) -> bool {
+        self.config
+            .min_improvement_generations
+            .is_some_and(|generations| self.unchanged >= generations)
+    }
+
+    /// Executa o algoritmo como [`Population::run`], chamando `callback` a cada geração.
+    pub fn run_with_callback(
+        &mut self,
+        mut callback: impl FnMut(&GenerationStats),
+    ) -> Result<SolveResult> {
+        let now = Instant::now();
+        let evaluations = self.evaluations();
+
+        let record_area = self.config.convergence_area;
+        let mut area = 0.0;
+
+        let mut generations = self.generations();
+        for stats in &mut generations {
+            let stats = stats?;
+            if record_area {
+                area += stats.best_fitness;
+            }
+            callback(&stats);
+        }
+        let timed_out = generations.timed_out;
+
+        self.finish_run(now, evaluations, timed_out, record_area.then_some(area))
+    }
+
+    /// `true` se o melhor indivíduo se moveu menos que `best_delta` desde a geração anterior
+    /// ou, com `relative_delta`, menos que essa fração da norma de `last_best`.
+    ///
+    /// Com `relative_delta`, `best_delta` não vale: em raízes muito pequenas, todo movimento
+    /// seria menor que ele. Resta só o piso [`RELATIVE_DELTA_FLOOR`], para raízes em zero.
+    fn moved_less_than_delta(&self, best: &[f64], last_best: &[f64]) -> bool {
+        let moved = distance(best, last_best);
+        match self.config.relative_delta {
+            Some(relative) => moved < (relative * norm(last_best)).max(RELATIVE_DELTA_FLOOR),
+            None => moved < self.config.best_delta,
+        }
+    }
+
+    /// Aplica o `polish`, se configurado, e monta o resultado de uma execução iniciada em
+    /// `start`, quando o contador de avaliações valia `evaluations`.
+    pub(crate) fn finish_run(
+        &mut self,
+        start: Instant,
+        evaluations: u64,
+        timed_out: bool,
+        convergence_area: Option<f64>,
+    ) -> Result<SolveResult> {
+        if self.config.polish && self.config.objective.is_root() {
+            let polished = self.polish(POLISH_ITERATIONS)?;
+            self.global_fitness = Some(self.fitness(&polished));
+            self.global_best = Some(polished);
+        }
+
+        let root = self
+            .global_best
+            .clone()
+            .ok_or(RootfnError::EmptyPopulation)?;
+        let fitness = self
+            .global_best_fitness()
+            .ok_or(RootfnError::EmptyPopulation)?;
+        let converged = self.converged(fitness);
+
+        let expected_root = self
+            .config
+            .expected_roots
+            .iter()
+            .min_by(|a, b| distance(a, &root).total_cmp(&distance(b, &root)))
+            .cloned();
+        let root_error = expected_root
+            .as_ref()
+            .map(|expected| distance(expected, &root));
+
+        Ok(SolveResult {
+            expected_root,
+            root_error,
+            root,
+            fitness,
+            generations: self.generation,
+            duration: start.elapsed(),
+            evaluations: self.evaluations() - evaluations,
+            converged,
+            timed_out,
+            stalled_out: !converged && self.stalled(),
+            convergence_area,
+        })
+    }
+
+    /// Refina o `global_best` com até `iterations` passos do método de Newton, usando a
+    /// derivada numérica de [`solvers::derivative`].
+    ///
+    /// O refinamento para quando a derivada se anula ou um passo piora a *fitness*, e
+    /// nunca retorna algo pior que o resultado do algoritmo genético. Só se aplica a
+    /// funções de uma variável; para sistemas, retorna o `global_best` sem alterações.
+    pub fn polish(&self, iterations: usize) -> Result<Genome> {
+        let best = self
+            .global_best
+            .clone()
+            .ok_or(RootfnError::EmptyPopulation)?;
+
+        if !self.target.is_scalar() {
+            return Ok(best);
+        }
+
+        let f = |x: f64| self.eval(&[x]);
+        let mut x = best[0];
+        let mut fitness = self.fitness(&best);
+
+        for _ in 0..iterations {
+            if fitness == 0.0 {
+                break;
+            }
+
+            let derivative = solvers::derivative(&f, x, solvers::derivative_step(x));
+            if derivative == 0.0 || !derivative.is_finite() {
+                break;
+            }
+
+            let next = x - f(x) / derivative;
+            let next_fitness = self.fitness(&[next]);
+            if !next.is_finite() || next_fitness.is_nan() || !self.is_better(next_fitness, fitness)
+            {
+                break;
+            }
+
+            x = next;
+            fitness = next_fitness;
+        }
+
+        Ok(vec![x])
+    }
+
+    /// Hiperparâmetros da população.
+    #[cfg(feature = "serde")]
+    pub(crate) fn config(&self) -> &Config {
+        &self.config
+    }
+
+    /// Estatísticas de cada geração da última execução. Vazio sem `record_history`.
+    pub fn history(&self) -> &[GenerationStats] {
+        &self.history
+    }
+
+    /// Indivíduos de cada geração da última execução. Vazio sem `record_snapshots`.
+    pub fn snapshots(&self) -> &[Vec<Genome>] {
+        &self.snapshots
+    }
+
+    /// Exporta o [`Population::history`] em CSV, uma linha por geração.
+    ///
+    /// As colunas são a geração, a *fitness* do melhor indivíduo, a média dos valores da
+    /// função e uma coluna `best_i` para cada componente do melhor indivíduo.
+    pub fn export_csv(&self, path: &Path) -> Result<()> {
+        let mut file = BufWriter::new(File::create(path)?);
+
+        write!(file, "generation,best_fitness,average")?;
+        for i in 0..self.dimension() {
+            write!(file, ",best_{}", i)?;
+        }
+        writeln!(file)?;
+
+        for stats in &self.history {
+            write!(
+                file,
+                "{},{},{}",
+                stats.generation, stats.best_fitness, stats.average
+            )?;
+            for x in &stats.best {
+                write!(file, ",{}", x)?;
+            }
+            writeln!(file)?;
+        }
+
+        file.flush()?;
+        Ok(())
+    }
+}
+
+/// Iterador sobre as gerações de uma população, criado por [`Population::generations`].
+pub struct Generations<'a, R = StdRng> {
+    population: &'a mut Population<R>,
+    start: Instant,
+    /// A última geração parou por `max_duration`.
+    timed_out: bool,
+    done: bool,
+}
+
+impl<R: Rng + MaybeSync> Iterator for Generations<'_, R> {
+    type Item = Result<GenerationStats>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+
+        let stats = self.population.step().and_then(|stats| {
+            let (stop, timed_out) = self.population.stop_condition(self.start)?;
+            self.done = stop;
+            self.timed_out = timed_out;
+            Ok(stats)
+        });
+        if stats.is_err() {
+            self.done = true;
+        }
+
+        Some(stats)
+    }
+}
+
+/// Média dos valores finitos de `values`, ou zero se não houver nenhum.
+fn mean(values: &[f64]) -> f64 {
+    let finite: Vec<f64> = values.iter().copied().filter(|x| x.is_finite()).collect();
+    if finite.is_empty() {
+        0.0
+    } else {
+        finite.iter().sum::<f64>() / finite.len() as f64
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        config::{FITNESS_TOLERANCE, INITIAL_INTERVAL, POPULATION_SIZE},
+        functions, Initialization, MutationDecay, PopulationBuilder,
+    };
+
+    fn cubic() -> PopulationBuilder {
+        PopulationBuilder::new(functions::CUBIC.function).seed(1)
+    }
+
+    #[test]
+    fn mutation_scale_decreases_as_fitness_improves() {
+        for decay in [MutationDecay::Linear, MutationDecay::Exponential] {
+            let mut pop = cubic()
+                .selection(Selection::Elitism)
+                .adaptive_mutation(decay)
+                .max_generations(500)
+                .build()
+                .unwrap();
+
+            let mut scales = Vec::new();
+            for _ in 0..500 {
+                let stats = pop.step().unwrap();
+                scales.push(pop.current_mutation_scale());
+                if stats.best_fitness < FITNESS_TOLERANCE {
+                    break;
+                }
+            }
+
+            assert!(scales.windows(2).all(|w| w[1] <= w[0]), "{decay:?}");
+            assert!(scales.last() < scales.first(), "{decay:?}");
+        }
+    }
+
+    #[test]
+    fn logarithm_converges_inside_domain() {
+        let mut pop = PopulationBuilder::new(|x| x.ln() - 1.0)
+            .domain(0.0..100.0)
+            .seed(1)
+            .build()
+            .unwrap();
+
+        let result = pop.run(false).unwrap();
+        assert!(result.converged);
+        assert!((result.root[0] - std::f64::consts::E).abs() < 1e-3);
+        assert!(pop.ind.iter().flatten().all(|x| (0.0..=100.0).contains(x)));
+    }
+
+    #[test]
+    fn domains_restrict_each_dimension() {
+        let system = || PopulationBuilder::system(2, |x| vec![x[0] - 0.5, x[1] - 15.0]).seed(1);
+        let domains = [0.0..=1.0, 10.0..=20.0];
+        let mut pop = system()
+            .domains(vec![0.0..1.0, 10.0..20.0])
+            .max_generations(50)
+            .build()
+            .unwrap();
+
+        pop.run(false).unwrap();
+        assert!(pop
+            .ind
+            .iter()
+            .all(|x| x.iter().zip(&domains).all(|(x, domain)| domain.contains(x))));
+
+        let mismatched = system().domains(vec![0.0..1.0]).build();
+        assert!(matches!(mismatched, Err(RootfnError::InvalidConfig(_))));
+    }
+
+    fn assert_valid_ranges(pop: &Population) {
+        for range in &pop.range {
+            assert!(
+                range.start.is_finite() && range.end.is_finite(),
+                "{range:?}"
+            );
+            assert!(range.start < range.end, "{range:?}");
+        }
+    }
+
+    #[test]
+    fn genocide_range_is_never_empty_or_inverted() {
+        for center in [0.0, -1e-300, -478.0, 1e15, -1e300, 1e300] {
+            let mut pop = cubic().population_size(10).build().unwrap();
+            pop.global_best = Some(vec![center]);
+            for _ in 0..100 {
+                pop.genocide().unwrap();
+                assert_valid_ranges(&pop);
+                assert!(pop.range[0].start <= center && center <= pop.range[0].end);
+            }
+        }
+    }
+
+    #[test]
+    fn repeated_genocide_never_panics() {
+        let mut pop = cubic()
+            .population_size(10)
+            .initial_interval(-5000.0..-100.0)
+            .build()
+            .unwrap();
+        for _ in 0..5000 {
+            pop.step().unwrap();
+            pop.genocide().unwrap();
+            assert_valid_ranges(&pop);
+        }
+    }
+
+    #[test]
+    fn empty_population_is_an_error() {
+        let mut pop = cubic().build().unwrap();
+        pop.ind.clear();
+        pop.cache.clear();
+        assert!(matches!(
+            pop.best_index(),
+            Err(RootfnError::EmptyPopulation)
+        ));
+        assert!(matches!(pop.run(false), Err(RootfnError::EmptyPopulation)));
+    }
+
+    #[test]
+    fn predation_draws_within_current_range() {
+        let mut pop = cubic().predation_count(20).build().unwrap();
+        pop.range = vec![470.0..480.0];
+
+        for _ in 0..50 {
+            let before = pop.ind.clone();
+            pop.random_predation().unwrap();
+            let replaced: Vec<&Genome> = pop.ind.iter().filter(|x| !before.contains(x)).collect();
+            assert_eq!(replaced.len(), 20);
+            assert!(replaced.iter().all(|x| pop.range[0].contains(&x[0])));
+        }
+    }
+
+    #[test]
+    fn tight_bracket_converges_faster() {
+        let run = |interval: Range<f64>| {
+            cubic()
+                .rearrangement(Rearrangement::Genocide)
+                .initial_interval(interval)
+                .max_generations(5_000)
+                .build()
+                .unwrap()
+                .run(false)
+                .unwrap()
+        };
+
+        let tight = run(400.0..500.0);
+        let wide = run(INITIAL_INTERVAL);
+        assert!(tight.converged);
+        assert!(tight.generations < wide.generations);
+    }
+
+    #[test]
+    fn annealing_accepts_mutations_of_an_infeasible_child() {
+        let mut pop = PopulationBuilder::new(|_| f64::NAN)
+            .seed(1)
+            .annealing(crate::Annealing {
+                initial_temperature: 1.0,
+                cooling: 0.5,
+            })
+            .build()
+            .unwrap();
+        for _ in 0..10 {
+            let child = vec![1.0];
+            assert_ne!(pop.mutate_child(child.clone()), child);
+        }
+    }
+
+    #[test]
+    fn relative_delta_does_not_stagnate_tiny_roots() {
+        let mut pop = PopulationBuilder::new(|x| x - 3e-10)
+            .seed(1)
+            .initial_interval(0.0..1e-9)
+            .mutation(MutationKind::Relative { fraction: 0.1 })
+            .fitness_tolerance(1e-30)
+            .rearrangement(Rearrangement::Genocide)
+            .counter_genocide(1)
+            .relative_delta(1e-6)
+            .build()
+            .unwrap();
+        // O genocídio descarta o `best`. Com o `best_delta` absoluto, ele seria aplicado a
+        // cada duas gerações, sempre que houvesse um `last_best` para comparar.
+        let mut genocides = 0;
+        for _ in 0..100 {
+            pop.step().unwrap();
+            if pop.best.is_none() {
+                genocides += 1;
+            }
+        }
+        assert!(genocides < 40, "{genocides} genocides");
+    }
+
+    #[test]
+    fn relative_mutation_converges_on_a_rescaled_cubic() {
+        let result = PopulationBuilder::new(|x| (functions::CUBIC.function)(x / 1000.0))
+            .seed(1)
+            .initial_interval(-1e6..1e6)
+            .mutation(MutationKind::Relative { fraction: 0.1 })
+            .rearrangement(Rearrangement::Genocide)
+            .build()
+            .unwrap()
+            .run(false)
+            .unwrap();
+        assert!(result.converged);
+        assert!(
+            (result.root[0] - 478_000.0).abs() < 1e-3,
+            "{:?}",
+            result.root
+        );
+    }
+
+    #[test]
+    fn deduplicate_keeps_a_diversity_floor() {
+        let min_diversity = |deduplicate: bool| {
+            let mut pop = cubic()
+                .selection(Selection::Elitism)
+                .best_delta(1e-2)
+                .deduplicate(deduplicate)
+                .build()
+                .unwrap();
+            (0..300)
+                .map(|_| {
+                    pop.step().unwrap();
+                    pop.diversity()
+                })
+                .skip(20)
+                .fold(f64::INFINITY, f64::min)
+        };
+
+        assert!(min_diversity(true) > 1.0);
+        assert!(min_diversity(false) < 1.0);
+    }
+
+    #[test]
+    fn larger_tournaments_converge_faster_and_lose_diversity_sooner() {
+        // Soma de gerações até convergir e da diversidade após cinco gerações, em dez sementes.
+        let measure = |size: usize| {
+            let builder = |seed| {
+                PopulationBuilder::new(functions::CUBIC.function)
+                    .seed(seed)
+                    .selection(Selection::Tournament { size })
+                    .rearrangement(Rearrangement::Genocide)
+                    .initial_interval(400.0..500.0)
+                    .max_generations(5_000)
+            };
+            (0..10).fold((0, 0.0), |(generations, diversity), seed| {
+                let result = builder(seed).build().unwrap().run(false).unwrap();
+                assert!(result.converged);
+                let mut pop = builder(seed).build().unwrap();
+                for _ in 0..5 {
+                    pop.step().unwrap();
+                }
+                (
+                    generations + result.generations,
+                    diversity + pop.diversity(),
+                )
+            })
+        };
+
+        let (binary_generations, binary_diversity) = measure(2);
+        let (large_generations, large_diversity) = measure(8);
+        assert!(large_generations < binary_generations);
+        assert!(large_diversity < binary_diversity);
+    }
+
+    #[test]
+    fn nan_function_has_no_finite_fitness() {
+        let mut pop = PopulationBuilder::new(|_| f64::NAN).build().unwrap();
+        assert!(matches!(pop.run(false), Err(RootfnError::NoFiniteFitness)));
+    }
+
+    #[test]
+    fn zero_trials_is_invalid() {
+        let mut pop = cubic().build().unwrap();
+        assert!(matches!(
+            pop.run_trials(0),
+            Err(RootfnError::InvalidConfig(_))
+        ));
+    }
+
+    #[test]
+    fn overflowing_interval_widths_are_invalid() {
+        // Ambos entravam em pânico ao sortear a população ou a primeira mutação.
+        let initial = cubic().initial_interval(-1e308..1e308).build();
+        assert!(matches!(initial, Err(RootfnError::InvalidConfig(_))));
+
+        let mutation = cubic()
+            .mutation(MutationKind::Uniform {
+                interval: -1e308..1e308,
+                rate: 1.0,
+            })
+            .build();
+        assert!(matches!(mutation, Err(RootfnError::InvalidConfig(_))));
+    }
+
+    #[test]
+    fn huge_blx_alpha_runs_without_panicking() {
+        // A cúbica estouraria nesses pontos; `atan` mantém a *fitness* finita.
+        let mut pop = PopulationBuilder::new(f64::atan)
+            .seed(1)
+            .crossover(Crossover::BlxAlpha { alpha: 1e307 })
+            .initial_interval(-1e300..1e300)
+            .max_generations(20)
+            .build()
+            .unwrap();
+        assert!(pop.run(false).is_ok());
+    }
+
+    #[cfg(feature = "plot")]
+    #[test]
+    fn unwritable_plot_dir_is_a_plot_error() {
+        // Um arquivo no lugar de um diretório pai impede a criação de `plot_dir`.
+        let file = std::env::temp_dir().join(format!("rootfn-plot-io-{}", std::process::id()));
+        std::fs::write(&file, "").unwrap();
+        let mut pop = cubic()
+            .max_generations(5)
+            .plot_dir(file.join("plots"))
+            .build()
+            .unwrap();
+
+        let result = pop.run(true);
+        std::fs::remove_file(&file).unwrap();
+        assert!(matches!(result, Err(RootfnError::PlotIo(_))));
+    }
+
+    #[test]
+    fn weighted_child_is_on_the_fitter_side() {
+        let mut pop = cubic()
+            .crossover(Crossover::WeightedArithmetic)
+            .build()
+            .unwrap();
+
+        for (fitter, other) in [
+            (478.5, 470.0),
+            (477.0, 500.0),
+            (-4567.1, -4000.0),
+            (1239.0, 0.0),
+        ] {
+            for (a, b) in [([fitter], [other]), ([other], [fitter])] {
+                let child = pop.recombine(&a, &b);
+                let midpoint = (fitter + other) / 2.0;
+                assert!(
+                    (child[0] - fitter).abs() < (midpoint - fitter).abs(),
+                    "{child:?}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn stratified_initialization_converges_faster_than_uniform() {
+        // Com uma população pequena, a cobertura inicial de cada faixa faz diferença.
+        let mean_generations = |initialization| {
+            let total: u64 = (0..40)
+                .map(|seed| {
+                    let result = PopulationBuilder::new(functions::CUBIC.function)
+                        .seed(seed)
+                        .population_size(10)
+                        .initial_interval(400.0..500.0)
+                        .initialization(initialization)
+                        .rearrangement(Rearrangement::Genocide)
+                        .build()
+                        .unwrap()
+                        .run(false)
+                        .unwrap();
+                    assert!(result.converged);
+                    result.generations
+                })
+                .sum();
+            total as f64 / 40.0
+        };
+
+        assert!(
+            mean_generations(Initialization::Stratified)
+                < mean_generations(Initialization::Uniform)
+        );
+    }
+
+    #[test]
+    fn global_best_never_gets_worse() {
+        for rearrangement in [
+            Rearrangement::Genocide,
+            Rearrangement::Restart,
+            Rearrangement::RandomPredation,
+        ] {
+            let mut pop = cubic()
+                .rearrangement(rearrangement)
+                .counter_genocide(1)
+                .predation_count(50)
+                .build()
+                .unwrap();
+
+            let mut last = f64::INFINITY;
+            for generation in 0..300 {
+                pop.step().unwrap();
+                match generation % 3 {
+                    0 => pop.genocide().unwrap(),
+                    1 => pop.restart().unwrap(),
+                    _ => pop.random_predation().unwrap(),
+                }
+
+                let global_best = pop.global_best.clone().unwrap();
+                let fitness = pop.global_best_fitness().unwrap();
+                assert_eq!(fitness, pop.fitness(&global_best));
+                assert!(fitness <= last, "{rearrangement:?}");
+                last = fitness;
+            }
+        }
+    }
+
+    #[test]
+    fn global_best_is_not_reevaluated() {
+        let mut pop = cubic()
+            .selection(Selection::Elitism)
+            .max_generations(100)
+            .build()
+            .unwrap();
+        let result = pop.run(false).unwrap();
+
+        // A primeira geração avalia todos; as demais, todos menos o elite. Os filhos da
+        // última geração não chegam a ser avaliados.
+        let size = POPULATION_SIZE as u64;
+        assert_eq!(
+            result.evaluations,
+            size + (result.generations - 1) * (size - 1)
+        );
+    }
+
+    #[test]
+    fn genocide_keeps_best_fitness() {
+        // Perto da raiz, os indivíduos sorteados pelo genocídio costumam ser piores que o
+        // `global_best`, então só a cópia dele mantém a *fitness*.
+        let mut pop = cubic()
+            .initial_interval(400.0..500.0)
+            .genocide_keeps_best(true)
+            .build()
+            .unwrap();
+        for _ in 0..200 {
+            pop.step().unwrap();
+            pop.genocide().unwrap();
+            pop.update_cache();
+            let best = pop.fitness_at(pop.best_index().unwrap()).unwrap();
+            assert!(best <= pop.global_best_fitness().unwrap());
+        }
+    }
+
+    #[test]
+    fn sus_runs_without_elite() {
+        for elite_count in [0, 1, 5] {
+            let mut pop = cubic()
+                .selection(Selection::Sus)
+                .elite_count(elite_count)
+                .max_generations(200)
+                .build()
+                .unwrap();
+            assert!(pop.run(false).is_ok(), "elite count {elite_count}");
+        }
+    }
+}
@@ -0,0 +1,86 @@
+//! Barra de progresso de uma execução no terminal.
+
+use crate::GenerationStats;
+use indicatif::{ProgressBar, ProgressStyle};
+
+/// Número de passos da barra no modo [`Progress::fitness`].
+const FITNESS_STEPS: u64 = 1_000;
+
+/// O que a barra acompanha.
+#[derive(Debug, Clone, Copy)]
+enum Mode {
+    Generations,
+    /// Progresso de `log(fitness)` entre a primeira geração e a tolerância.
+    Fitness {
+        tolerance: f64,
+        initial: Option<f64>,
+    },
+}
+
+/// Mostra o progresso de uma execução em uma barra no terminal.
+///
+/// Feito para ser usado como *callback* de
+/// [`Population::run_with_callback`](crate::Population::run_with_callback). A barra não é
+/// desenhada quando a saída de erro não é um terminal.
+#[derive(Debug, Clone)]
+pub struct Progress {
+    bar: ProgressBar,
+    mode: Mode,
+}
+
+impl Progress {
+    /// Barra que avança com as gerações, até `max_generations`.
+    pub fn generations(max_generations: u64) -> Self {
+        let bar = ProgressBar::new(max_generations);
+        bar.set_style(
+            ProgressStyle::with_template("{bar:40} {pos}/{len} generations | {msg}")
+                .expect("template is valid"),
+        );
+        Self {
+            bar,
+            mode: Mode::Generations,
+        }
+    }
+
+    /// Barra que avança com o logaritmo da melhor *fitness*, da primeira geração até
+    /// `tolerance`.
+    pub fn fitness(tolerance: f64) -> Self {
+        let bar = ProgressBar::new(FITNESS_STEPS);
+        bar.set_style(
+            ProgressStyle::with_template("{bar:40} {percent}% | {msg}").expect("template is valid"),
+        );
+        Self {
+            bar,
+            mode: Mode::Fitness {
+                tolerance,
+                initial: None,
+            },
+        }
+    }
+
+    /// Atualiza a barra com uma geração.
+    pub fn record(&mut self, stats: &GenerationStats) {
+        let fitness = stats.best_fitness;
+        match &mut self.mode {
+            Mode::Generations => self.bar.set_position(stats.generation),
+            Mode::Fitness { tolerance, initial } => {
+                let initial = *initial.get_or_insert(fitness);
+                let progress = if initial <= *tolerance {
+                    1.0
+                } else {
+                    (initial.ln() - fitness.max(*tolerance).ln()) / (initial.ln() - tolerance.ln())
+                };
+                if progress.is_finite() {
+                    let position = progress.clamp(0.0, 1.0) * FITNESS_STEPS as f64;
+                    self.bar.set_position(position as u64);
+                }
+            }
+        }
+        self.bar.set_message(format!("best fitness {:e}", fitness));
+    }
+
+    /// Encerra a barra, deixando-a na tela na posição em que parou.
+    pub fn finish(&self) {
+        self.bar.abandon();
+    }
+}
@@ -0,0 +1,171 @@
+use crate::{target::MaybeSync, Population, Result, RootfnError};
+use rand::{rngs::StdRng, Rng};
+use std::{fmt::Display, str::FromStr};
+
+/// Estratégia usada para reorganizar a população e manter a diversidade.
+///
+/// Cada variante corresponde a um [`Rearranger`]; `Custom` indica um definido pelo usuário
+/// com [`PopulationBuilder::rearranger`](crate::PopulationBuilder::rearranger). O padrão é
+/// [`Rearrangement::None`].
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum Rearrangement {
+    #[default]
+    None,
+    Genocide,
+    RandomPredation,
+    /// Sorteia toda a população de novo no intervalo inicial, mantendo o `global_best`.
+    Restart,
+    Custom,
+}
+
+impl Rearrangement {
+    /// Estratégia correspondente à variante, ou `None` para [`Rearrangement::Custom`].
+    pub(crate) fn rearranger<R: Rng + MaybeSync>(
+        self,
+        counter_genocide: u8,
+    ) -> Option<Box<dyn Rearranger<R>>> {
+        match self {
+            Rearrangement::None => Some(Box::new(NoRearrangement)),
+            Rearrangement::Genocide => Some(Box::new(Genocide {
+                counter: counter_genocide,
+            })),
+            Rearrangement::RandomPredation => Some(Box::new(RandomPredation)),
+            Rearrangement::Restart => Some(Box::new(Restart {
+                counter: counter_genocide,
+            })),
+            Rearrangement::Custom => None,
+        }
+    }
+}
+
+impl Display for Rearrangement {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Rearrangement::None => write!(f, ""),
+            Rearrangement::Genocide => write!(f, "genocide"),
+            Rearrangement::RandomPredation => write!(f, "random_predation"),
+            Rearrangement::Restart => write!(f, "restart"),
+            Rearrangement::Custom => write!(f, "custom"),
+        }
+    }
+}
+
+impl FromStr for Rearrangement {
+    type Err = RootfnError;
+
+    /// Aceita os nomes produzidos por [`Display`], além de `none` para
+    /// [`Rearrangement::None`].
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        match s {
+            "" | "none" => Ok(Rearrangement::None),
+            "genocide" => Ok(Rearrangement::Genocide),
+            "random_predation" => Ok(Rearrangement::RandomPredation),
+            "restart" => Ok(Rearrangement::Restart),
+            _ => Err(RootfnError::InvalidConfig(format!(
+                "unknown rearrangement `{}`",
+                s
+            ))),
+        }
+    }
+}
+
+/// Serializa com o mesmo nome produzido por [`Display`].
+#[cfg(feature = "serde")]
+impl serde::Serialize for Rearrangement {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+/// Estratégia que reorganiza a população ao fim de cada geração para manter a diversidade.
+///
+/// Implemente para usar uma estratégia própria com
+/// [`PopulationBuilder::rearranger`](crate::PopulationBuilder::rearranger). `R` é o gerador
+/// de números aleatórios da população.
+pub trait Rearranger<R = StdRng>: MaybeSync {
+    /// Decide se a estratégia deve ser aplicada nesta geração.
+    fn should_apply(&self, pop: &Population<R>) -> bool;
+
+    /// Reorganiza a população.
+    fn apply(&mut self, pop: &mut Population<R>) -> Result<()>;
+}
+
+/// Nunca reorganiza a população.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct NoRearrangement;
+
+impl<R: Rng + MaybeSync> Rearranger<R> for NoRearrangement {
+    fn should_apply(&self, _pop: &Population<R>) -> bool {
+        false
+    }
+
+    fn apply(&mut self, _pop: &mut Population<R>) -> Result<()> {
+        Ok(())
+    }
+}
+
+/// Aplica [`Population::genocide`] depois de `counter` gerações seguidas de estagnação.
+#[derive(Debug, Clone, Copy)]
+pub struct Genocide {
+    pub counter: u8,
+}
+
+impl<R: Rng + MaybeSync> Rearranger<R> for Genocide {
+    fn should_apply(&self, pop: &Population<R>) -> bool {
+        pop.stagnation() >= u64::from(self.counter)
+    }
+
+    fn apply(&mut self, pop: &mut Population<R>) -> Result<()> {
+        pop.genocide()
+    }
+}
+
+/// Aplica [`Population::restart`] depois de `counter` gerações seguidas de estagnação, como
+/// [`Genocide`].
+#[derive(Debug, Clone, Copy)]
+pub struct Restart {
+    pub counter: u8,
+}
+
+impl<R: Rng + MaybeSync> Rearranger<R> for Restart {
+    fn should_apply(&self, pop: &Population<R>) -> bool {
+        pop.stagnation() >= u64::from(self.counter)
+    }
+
+    fn apply(&mut self, pop: &mut Population<R>) -> Result<()> {
+        pop.restart()
+    }
+}
+
+/// Aplica [`Population::random_predation`] a cada geração.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct RandomPredation;
+
+impl<R: Rng + MaybeSync> Rearranger<R> for RandomPredation {
+    fn should_apply(&self, _pop: &Population<R>) -> bool {
+        true
+    }
+
+    fn apply(&mut self, pop: &mut Population<R>) -> Result<()> {
+        pop.random_predation()
+    }
+}
+
+/// O que fazer quando a diversidade da população colapsa, isto é, quando todos os
+/// indivíduos ficam a menos de `best_delta` uns dos outros.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum DiversityCollapse {
+    /// Continua a execução normalmente.
+    Continue,
+    /// Reorganiza a população: aplica imediatamente a estratégia configurada, seja a
+    /// predação aleatória, o reinício ou a [`Rearrangement::Custom`], e o genocídio com
+    /// [`Rearrangement::None`] ou [`Rearrangement::Genocide`].
+    Rearrange,
+    /// Encerra a execução.
+    Stop,
+}
@@ -0,0 +1,87 @@
+use crate::{functions, target::MaybeSync};
+use std::{collections::BTreeMap, sync::Arc};
+
+/// Função registrada, compartilhada entre as populações criadas a partir dela.
+#[cfg(feature = "rayon")]
+type SharedFn = Arc<dyn Fn(f64) -> f64 + Send + Sync>;
+#[cfg(not(feature = "rayon"))]
+type SharedFn = Arc<dyn Fn(f64) -> f64>;
+
+/// Função registrada e as suas raízes reais conhecidas.
+struct Entry {
+    function: SharedFn,
+    roots: Vec<f64>,
+}
+
+/// Funções de uma variável identificadas por nome, para escolher a função de uma execução a
+/// partir de uma *string*, como faz o binário com `--function`.
+///
+/// [`FunctionRegistry::default`] já vem com as funções de [`functions::ALL`];
+/// [`FunctionRegistry::new`] começa vazio.
+pub struct FunctionRegistry {
+    entries: BTreeMap<String, Entry>,
+}
+
+impl Default for FunctionRegistry {
+    fn default() -> Self {
+        let mut registry = Self::new();
+        for test in functions::ALL {
+            registry.register_with_roots(test.name, test.function, test.roots.to_vec());
+        }
+        registry
+    }
+}
+
+impl FunctionRegistry {
+    /// Cria um registro vazio.
+    pub fn new() -> Self {
+        Self {
+            entries: BTreeMap::new(),
+        }
+    }
+
+    /// Registra `f` com o nome `name`, substituindo a função que já tiver esse nome.
+    pub fn register(
+        &mut self,
+        name: impl Into<String>,
+        f: impl Fn(f64) -> f64 + MaybeSync + 'static,
+    ) -> &mut Self {
+        self.register_with_roots(name, f, Vec::new())
+    }
+
+    /// Como [`FunctionRegistry::register`], junto com as raízes reais conhecidas de `f`, que
+    /// podem ser passadas ao
+    /// [`PopulationBuilder::expected_roots`](crate::PopulationBuilder::expected_roots).
+    pub fn register_with_roots(
+        &mut self,
+        name: impl Into<String>,
+        f: impl Fn(f64) -> f64 + MaybeSync + 'static,
+        roots: Vec<f64>,
+    ) -> &mut Self {
+        let entry = Entry {
+            function: Arc::new(f),
+            roots,
+        };
+        self.entries.insert(name.into(), entry);
+        self
+    }
+
+    /// Função registrada com o nome `name`, pronta para o
+    /// [`PopulationBuilder::new`](crate::PopulationBuilder::new). Pode ser pedida várias
+    /// vezes; todas as cópias compartilham a mesma função.
+    pub fn get(&self, name: &str) -> Option<impl Fn(f64) -> f64 + MaybeSync + 'static> {
+        let function = Arc::clone(&self.entries.get(name)?.function);
+        Some(move |x| function(x))
+    }
+
+    /// Raízes conhecidas da função registrada com o nome `name`, vazias se não foram
+    /// informadas.
+    pub fn roots(&self, name: &str) -> Option<&[f64]> {
+        self.entries.get(name).map(|entry| entry.roots.as_slice())
+    }
+
+    /// Nomes das funções registradas, em ordem alfabética.
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.entries.keys().map(String::as_str)
+    }
+}
@@ -0,0 +1,29 @@
+use crate::{
+    config::Config, target::MaybeSync, Population, Rearrangement, Result, Selection, SolveResult,
+};
+use rand::Rng;
+use serde::Serialize;
+
+/// Configuração e resultado de uma execução, no formato serializado por
+/// [`Population::to_json`].
+#[derive(Serialize)]
+struct Report<'a> {
+    selection: Selection,
+    rearrangement: Rearrangement,
+    config: &'a Config,
+    result: &'a SolveResult,
+}
+
+impl<R: Rng + MaybeSync> Population<R> {
+    /// Serializa em JSON a estratégia, os hiperparâmetros e o resultado de uma execução,
+    /// em uma única linha.
+    pub fn to_json(&self, result: &SolveResult) -> Result<String> {
+        let report = Report {
+            selection: self.selection(),
+            rearrangement: self.rearrangement(),
+            config: self.config(),
+            result,
+        };
+        Ok(serde_json::to_string(&report)?)
+    }
+}
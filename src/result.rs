@@ -0,0 +1,217 @@
+use crate::{genome::distance, Genome};
+use std::{cmp::Reverse, ops::Range, time::Duration};
+
+/// Resultado de uma execução de [`Population::run`](crate::Population::run).
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct SolveResult {
+    /// Melhor indivíduo encontrado.
+    pub root: Genome,
+    /// *Fitness* do melhor indivíduo.
+    pub fitness: f64,
+    /// Número de gerações executadas.
+    pub generations: u64,
+    /// Tempo total da execução.
+    pub duration: Duration,
+    /// Número de avaliações da função feitas durante a execução.
+    pub evaluations: u64,
+    /// `true` se a tolerância foi atingida antes do número máximo de gerações.
+    pub converged: bool,
+    /// `true` se a execução foi interrompida por `max_duration`.
+    pub timed_out: bool,
+    /// `true` se a execução foi interrompida porque o melhor indivíduo não melhorou por
+    /// [`PopulationBuilder::min_improvement_generations`](crate::PopulationBuilder::min_improvement_generations)
+    /// gerações seguidas.
+    pub stalled_out: bool,
+    /// Raiz esperada mais próxima de `root`, se alguma foi registrada com
+    /// [`PopulationBuilder::expected_roots`](crate::PopulationBuilder::expected_roots).
+    pub expected_root: Option<Genome>,
+    /// Distância entre `root` e `expected_root`.
+    pub root_error: Option<f64>,
+    /// Área sob a curva do melhor *fitness* de cada geração, a soma desses valores. Quanto
+    /// menor, mais rápida a convergência. Calculada apenas com
+    /// [`PopulationBuilder::convergence_area`](crate::PopulationBuilder::convergence_area).
+    pub convergence_area: Option<f64>,
+}
+
+/// Estatísticas de uma geração, passadas a
+/// [`Population::run_with_callback`](crate::Population::run_with_callback).
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct GenerationStats {
+    /// Número da geração.
+    pub generation: u64,
+    /// Melhor indivíduo da geração.
+    pub best: Genome,
+    /// *Fitness* de `best`.
+    pub best_fitness: f64,
+    /// Melhor indivíduo encontrado até esta geração.
+    pub global_best: Genome,
+    /// Média dos valores da função na população, como em
+    /// [`Population::average_value`](crate::Population::average_value).
+    pub average: f64,
+    /// Média da *fitness* na população.
+    pub average_fitness: f64,
+    /// Dispersão da população, como em [`Population::diversity`](crate::Population::diversity).
+    pub diversity: f64,
+    /// Tempo gasto na geração, incluindo a seleção e a reorganização. Medido apenas com
+    /// [`PopulationBuilder::time_generations`](crate::PopulationBuilder::time_generations).
+    pub duration: Option<Duration>,
+}
+
+/// Resumo de várias execuções independentes, retornado por
+/// [`Population::run_trials`](crate::Population::run_trials).
+///
+/// As estatísticas de gerações consideram apenas as execuções que convergiram, e são `None`
+/// se nenhuma convergiu.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct TrialStats {
+    /// Número de execuções.
+    pub trials: usize,
+    /// Fração das execuções que atingiram a tolerância.
+    pub success_rate: f64,
+    /// Média de gerações até convergir.
+    pub mean_generations: Option<f64>,
+    /// Mediana de gerações até convergir.
+    pub median_generations: Option<f64>,
+    /// Desvio padrão das gerações até convergir.
+    pub std_generations: Option<f64>,
+    /// Menor *fitness* final entre as execuções.
+    pub best_fitness: f64,
+    /// Maior *fitness* final entre as execuções.
+    pub worst_fitness: f64,
+    /// Raízes das execuções que convergiram, agrupadas por proximidade, da mais encontrada
+    /// para a menos encontrada.
+    pub clusters: Vec<RootCluster>,
+}
+
+/// Raízes próximas encontradas por execuções diferentes de
+/// [`Population::run_trials`](crate::Population::run_trials), que provavelmente estimam a
+/// mesma raiz verdadeira.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct RootCluster {
+    /// Número de execuções cuja raiz está no agrupamento.
+    pub count: usize,
+    /// Média das raízes, a estimativa da raiz verdadeira.
+    pub mean: Genome,
+    /// Desvio padrão das raízes em cada dimensão.
+    pub std: Genome,
+}
+
+impl RootCluster {
+    /// Intervalo de confiança da média em cada dimensão, `mean ± z * std / √count`. Com
+    /// `z = 1.96`, o nível de confiança é de aproximadamente 95%.
+    pub fn confidence_interval(&self, z: f64) -> Vec<Range<f64>> {
+        let scale = z / (self.count as f64).sqrt();
+        self.mean
+            .iter()
+            .zip(&self.std)
+            .map(|(mean, std)| mean - scale * std..mean + scale * std)
+            .collect()
+    }
+
+    /// Agrupa `roots`: cada raiz entra no primeiro agrupamento cuja média está a menos de
+    /// `radius` dela, ou começa um novo.
+    fn from_roots(roots: &[&Genome], radius: f64) -> Vec<Self> {
+        let mut members: Vec<Vec<&Genome>> = Vec::new();
+        let mut means: Vec<Genome> = Vec::new();
+
+        for &root in roots {
+            match means.iter().position(|mean| distance(mean, root) <= radius) {
+                Some(i) => {
+                    members[i].push(root);
+                    means[i] = mean(&members[i]);
+                }
+                None => {
+                    members.push(vec![root]);
+                    means.push(root.clone());
+                }
+            }
+        }
+
+        let mut clusters: Vec<Self> = members
+            .iter()
+            .zip(means)
+            .map(|(members, mean)| {
+                let n = members.len() as f64;
+                let std = (0..mean.len())
+                    .map(|d| {
+                        let variance = members
+                            .iter()
+                            .map(|r| (r[d] - mean[d]).powi(2))
+                            .sum::<f64>()
+                            / n;
+                        variance.sqrt()
+                    })
+                    .collect();
+                Self {
+                    count: members.len(),
+                    mean,
+                    std,
+                }
+            })
+            .collect();
+        clusters.sort_by_key(|cluster| Reverse(cluster.count));
+        clusters
+    }
+}
+
+/// Média componente a componente de `genomes`, que não pode ser vazio.
+fn mean(genomes: &[&Genome]) -> Genome {
+    let n = genomes.len() as f64;
+    (0..genomes[0].len())
+        .map(|d| genomes.iter().map(|g| g[d]).sum::<f64>() / n)
+        .collect()
+}
+
+impl TrialStats {
+    /// Resume os resultados de `results`, que não pode ser vazio, agrupando as raízes a
+    /// menos de `radius` umas das outras.
+    pub(crate) fn from_results(results: &[SolveResult], radius: f64) -> Self {
+        let trials = results.len();
+        let mut generations: Vec<f64> = results
+            .iter()
+            .filter(|result| result.converged)
+            .map(|result| result.generations as f64)
+            .collect();
+        generations.sort_by(f64::total_cmp);
+        let roots: Vec<&Genome> = results
+            .iter()
+            .filter(|result| result.converged)
+            .map(|result| &result.root)
+            .collect();
+
+        let n = generations.len();
+        let (mean, median, std) = if n == 0 {
+            (None, None, None)
+        } else {
+            let mean = generations.iter().sum::<f64>() / n as f64;
+            let median = if n.is_multiple_of(2) {
+                (generations[n / 2 - 1] + generations[n / 2]) / 2.0
+            } else {
+                generations[n / 2]
+            };
+            let variance = generations.iter().map(|g| (g - mean).powi(2)).sum::<f64>() / n as f64;
+            (Some(mean), Some(median), Some(variance.sqrt()))
+        };
+
+        Self {
+            trials,
+            success_rate: n as f64 / trials as f64,
+            mean_generations: mean,
+            median_generations: median,
+            std_generations: std,
+            best_fitness: results
+                .iter()
+                .map(|result| result.fitness)
+                .fold(f64::INFINITY, f64::min),
+            worst_fitness: results
+                .iter()
+                .map(|result| result.fitness)
+                .fold(f64::NEG_INFINITY, f64::max),
+            clusters: RootCluster::from_roots(&roots, radius),
+        }
+    }
+}
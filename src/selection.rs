@@ -0,0 +1,164 @@
+use crate::{Result, RootfnError};
+use std::{fmt::Display, str::FromStr};
+
+/// Número de candidatos do torneio padrão.
+const TOURNAMENT_SIZE: usize = 2;
+
+/// Fator de escala padrão da evolução diferencial.
+const DIFFERENTIAL_WEIGHT: f64 = 0.8;
+
+/// Probabilidade de cruzamento padrão da evolução diferencial.
+const DIFFERENTIAL_CROSSOVER: f64 = 0.9;
+
+/// Estratégia usada para gerar a próxima geração. O padrão é [`Selection::Tournament`] com
+/// dois candidatos.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Selection {
+    Elitism,
+    /// Cada pai é o melhor de `size` indivíduos sorteados. Torneios maiores aumentam a
+    /// pressão seletiva: a população converge mais rápido, mas perde diversidade mais cedo.
+    Tournament {
+        size: usize,
+    },
+    /// Seleção proporcional ao inverso da *fitness*.
+    Roulette,
+    /// Amostragem universal estocástica: como a roleta, mas com todos os pais sorteados
+    /// de uma vez, com menor variância.
+    Sus,
+    /// Seleção proporcional à posição de cada indivíduo no *ranking* de *fitness*.
+    Rank {
+        ranking: Ranking,
+    },
+    /// Evolução diferencial: para cada indivíduo, monta um vetor `a + f * (b - c)` a partir
+    /// de outros três sorteados, mistura-o com o indivíduo componente a componente com
+    /// probabilidade `cr` e fica com o vetor apenas se ele for melhor.
+    ///
+    /// Substitui o cruzamento e a mutação configurados. Como nenhum indivíduo piora, não
+    /// precisa de elite. Exige ao menos quatro indivíduos.
+    DifferentialEvolution {
+        f: f64,
+        cr: f64,
+    },
+}
+
+/// Como a posição no *ranking* é convertida em probabilidade de seleção.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Ranking {
+    /// Peso decresce linearmente com a posição.
+    Linear,
+    /// Peso decresce geometricamente com a posição.
+    Exponential,
+}
+
+impl Default for Selection {
+    fn default() -> Self {
+        Selection::Tournament {
+            size: TOURNAMENT_SIZE,
+        }
+    }
+}
+
+impl Selection {
+    pub(crate) fn validate(&self, population_size: usize) -> Result<()> {
+        match self {
+            Selection::Tournament { size: 0 } => Err(RootfnError::InvalidConfig(
+                "tournament size must be greater than zero".to_string(),
+            )),
+            Selection::DifferentialEvolution { f, cr } => {
+                if !(*f > 0.0 && f.is_finite()) {
+                    return Err(RootfnError::InvalidConfig(
+                        "differential evolution weight must be finite and positive".to_string(),
+                    ));
+                }
+                if !(0.0..=1.0).contains(cr) {
+                    return Err(RootfnError::InvalidConfig(
+                        "differential evolution crossover must be in [0, 1]".to_string(),
+                    ));
+                }
+                if population_size < 4 {
+                    return Err(RootfnError::InvalidConfig(
+                        "differential evolution requires at least four individuals".to_string(),
+                    ));
+                }
+                Ok(())
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+impl Display for Selection {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Selection::Elitism => write!(f, "elitism"),
+            Selection::Tournament {
+                size: TOURNAMENT_SIZE,
+            } => write!(f, "tournament"),
+            Selection::Tournament { size } => write!(f, "tournament_{}", size),
+            Selection::Roulette => write!(f, "roulette"),
+            Selection::Sus => write!(f, "sus"),
+            Selection::Rank { ranking } => write!(f, "rank_{}", ranking),
+            Selection::DifferentialEvolution { .. } => write!(f, "differential_evolution"),
+        }
+    }
+}
+
+impl Display for Ranking {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Ranking::Linear => write!(f, "linear"),
+            Ranking::Exponential => write!(f, "exponential"),
+        }
+    }
+}
+
+impl FromStr for Selection {
+    type Err = RootfnError;
+
+    /// Aceita os mesmos nomes produzidos por [`Display`]: `tournament` para o torneio padrão
+    /// e `tournament_{size}` para os demais. `differential_evolution` usa `f = 0.8` e
+    /// `cr = 0.9`.
+    fn from_str(s: &str) -> Result<Self> {
+        if let Some(size) = s.strip_prefix("tournament_") {
+            return match size.parse() {
+                Ok(size) => Ok(Selection::Tournament { size }),
+                Err(_) => Err(RootfnError::InvalidConfig(format!(
+                    "invalid tournament size `{}`",
+                    size
+                ))),
+            };
+        }
+
+        match s {
+            "elitism" => Ok(Selection::Elitism),
+            "tournament" => Ok(Selection::default()),
+            "roulette" => Ok(Selection::Roulette),
+            "sus" => Ok(Selection::Sus),
+            "rank_linear" => Ok(Selection::Rank {
+                ranking: Ranking::Linear,
+            }),
+            "rank_exponential" => Ok(Selection::Rank {
+                ranking: Ranking::Exponential,
+            }),
+            "differential_evolution" => Ok(Selection::DifferentialEvolution {
+                f: DIFFERENTIAL_WEIGHT,
+                cr: DIFFERENTIAL_CROSSOVER,
+            }),
+            _ => Err(RootfnError::InvalidConfig(format!(
+                "unknown selection `{}`",
+                s
+            ))),
+        }
+    }
+}
+
+/// Serializa com o mesmo nome produzido por [`Display`].
+#[cfg(feature = "serde")]
+impl serde::Serialize for Selection {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
@@ -0,0 +1,235 @@
+//! Métodos clássicos de busca de raízes, usados como referência para comparar com o
+//! algoritmo genético, além de utilitários como a derivada numérica, a busca de intervalos
+//! com mudança de sinal e a verificação de raízes.
+
+use std::{fmt::Display, ops::Range};
+
+/// Número máximo de iterações dos métodos deste módulo.
+pub const MAX_ITERATIONS: usize = 1_000;
+
+/// Erros dos métodos clássicos.
+#[derive(Debug, Clone, PartialEq)]
+pub enum SolverError {
+    /// `f(a)` e `f(b)` têm o mesmo sinal, então o intervalo não garante uma raiz.
+    InvalidBracket { a: f64, b: f64 },
+    /// A reta secante ficou horizontal: `f(x0) == f(x1)`.
+    FlatSecant { x0: f64, x1: f64 },
+    /// A função retornou `NaN` ou infinito em `x`.
+    NonFinite { x: f64 },
+    /// A tolerância não foi atingida em [`MAX_ITERATIONS`] iterações.
+    NoConvergence { last: f64 },
+}
+
+impl Display for SolverError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SolverError::InvalidBracket { a, b } => {
+                write!(f, "f({}) and f({}) have the same sign", a, b)
+            }
+            SolverError::FlatSecant { x0, x1 } => {
+                write!(f, "f({}) equals f({}), secant is horizontal", x0, x1)
+            }
+            SolverError::NonFinite { x } => write!(f, "function is not finite at {}", x),
+            SolverError::NoConvergence { last } => write!(
+                f,
+                "no convergence after {} iterations, last estimate {}",
+                MAX_ITERATIONS, last
+            ),
+        }
+    }
+}
+
+impl std::error::Error for SolverError {}
+
+/// Método da bisseção em `[a, b]`.
+///
+/// Para quando `|f(x)| < tol` ou quando o intervalo fica menor que `tol`.
+pub fn bisection(f: impl Fn(f64) -> f64, a: f64, b: f64, tol: f64) -> Result<f64, SolverError> {
+    let (mut a, mut b) = (a.min(b), a.max(b));
+    let mut fa = finite(&f, a)?;
+    let fb = finite(&f, b)?;
+
+    if fa == 0.0 {
+        return Ok(a);
+    }
+    if fb == 0.0 {
+        return Ok(b);
+    }
+    if fa.signum() == fb.signum() {
+        return Err(SolverError::InvalidBracket { a, b });
+    }
+
+    let mut mid = (a + b) / 2.0;
+    for _ in 0..MAX_ITERATIONS {
+        mid = (a + b) / 2.0;
+        let fm = finite(&f, mid)?;
+
+        if fm.abs() < tol || (b - a) / 2.0 < tol {
+            return Ok(mid);
+        }
+
+        if fm.signum() == fa.signum() {
+            a = mid;
+            fa = fm;
+        } else {
+            b = mid;
+        }
+    }
+
+    Err(SolverError::NoConvergence { last: mid })
+}
+
+/// Método da secante a partir de `x0` e `x1`.
+///
+/// Para quando `|f(x)| < tol` ou quando o passo fica menor que `tol`.
+pub fn secant(f: impl Fn(f64) -> f64, x0: f64, x1: f64, tol: f64) -> Result<f64, SolverError> {
+    let (mut x0, mut x1) = (x0, x1);
+    let mut f0 = finite(&f, x0)?;
+    let mut f1 = finite(&f, x1)?;
+
+    for _ in 0..MAX_ITERATIONS {
+        if f1.abs() < tol {
+            return Ok(x1);
+        }
+        if f1 == f0 {
+            return Err(SolverError::FlatSecant { x0, x1 });
+        }
+
+        let x2 = x1 - f1 * (x1 - x0) / (f1 - f0);
+        let f2 = finite(&f, x2)?;
+
+        if (x2 - x1).abs() < tol {
+            return Ok(x2);
+        }
+
+        (x0, f0) = (x1, f1);
+        (x1, f1) = (x2, f2);
+    }
+
+    Err(SolverError::NoConvergence { last: x1 })
+}
+
+/// Divide `range` em `samples` subintervalos iguais e retorna, em ordem, aqueles em que `f`
+/// muda de sinal, cada um contendo ao menos uma raiz.
+///
+/// Um ponto onde `f` vale exatamente zero conta como mudança de sinal no subintervalo que
+/// termina nele, ou no primeiro se for o início de `range`. Subintervalos com algum extremo
+/// não finito são ignorados. Raízes de multiplicidade par, que não mudam o sinal, e pares
+/// de raízes no mesmo subintervalo não são detectados.
+pub fn brackets(f: impl Fn(f64) -> f64, range: Range<f64>, samples: usize) -> Vec<Range<f64>> {
+    let mut brackets = Vec::new();
+    if samples == 0 {
+        return brackets;
+    }
+
+    let width = (range.end - range.start) / samples as f64;
+    let point = |i: usize| {
+        if i == samples {
+            range.end
+        } else {
+            range.start + width * i as f64
+        }
+    };
+
+    let mut a = point(0);
+    let mut fa = f(a);
+    for i in 1..=samples {
+        let b = point(i);
+        let fb = f(b);
+        if fa.is_finite() && fb.is_finite() && (fa * fb < 0.0 || fb == 0.0 || (i == 1 && fa == 0.0))
+        {
+            brackets.push(a..b);
+        }
+        (a, fa) = (b, fb);
+    }
+
+    brackets
+}
+
+/// Verifica se `f` muda de sinal entre `x - eps` e `x + eps`, confirmando que `x` está perto
+/// de uma raiz que cruza o zero.
+///
+/// Uma *fitness* abaixo da tolerância não basta: um mínimo local que apenas toca o zero, como
+/// `x²` em `0`, passa nela mas não muda de sinal, e aqui retorna `false`. Valores não finitos
+/// também retornam `false`. Útil para validar o
+/// [`SolveResult::root`](crate::SolveResult::root) de funções de uma variável.
+pub fn verify_root(f: impl Fn(f64) -> f64, x: f64, eps: f64) -> bool {
+    let before = f(x - eps);
+    let after = f(x + eps);
+    before.is_finite() && after.is_finite() && before * after < 0.0
+}
+
+/// Derivada de `f` em `x` pela diferença central com passo `h`.
+///
+/// Divide pela distância efetivamente representada entre `x + h` e `x - h`, e não por `2h`,
+/// para que o arredondamento de `x ± h` não distorça o resultado quando `x` é grande. Use
+/// [`derivative_step`] para um `h` adequado à magnitude de `x`.
+pub fn derivative(f: &dyn Fn(f64) -> f64, x: f64, h: f64) -> f64 {
+    let forward = x + h;
+    let backward = x - h;
+    (f(forward) - f(backward)) / (forward - backward)
+}
+
+/// Passo padrão para [`derivative`] em `x`: `ε^(1/3)` vezes a magnitude de `x`, ou vezes
+/// `1.0` perto de zero, equilibrando o erro de truncamento e o de cancelamento.
+pub fn derivative_step(x: f64) -> f64 {
+    f64::EPSILON.cbrt() * x.abs().max(1.0)
+}
+
+/// Avalia `f` em `x`, falhando se o resultado não for finito.
+fn finite(f: &impl Fn(f64) -> f64, x: f64) -> Result<f64, SolverError> {
+    let y = f(x);
+    if y.is_finite() {
+        Ok(y)
+    } else {
+        Err(SolverError::NonFinite { x })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{functions::CUBIC, PopulationBuilder, Rearrangement};
+
+    #[test]
+    fn bisection_secant_and_ga_agree_on_cubic_roots() {
+        for &root in CUBIC.roots {
+            let bisection = bisection(CUBIC.function, root - 100.0, root + 90.0, 1e-9).unwrap();
+            let secant = secant(CUBIC.function, root - 10.0, root + 5.0, 1e-9).unwrap();
+            let ga = PopulationBuilder::new(CUBIC.function)
+                .initial_interval(root - 100.0..root + 90.0)
+                .rearrangement(Rearrangement::Genocide)
+                .max_generations(5_000)
+                .seed(1)
+                .build()
+                .unwrap()
+                .run(false)
+                .unwrap();
+            assert!(ga.converged);
+
+            for estimate in [bisection, secant, ga.root[0]] {
+                assert!((estimate - root).abs() < 1e-6, "{estimate} != {root}");
+            }
+        }
+    }
+
+    #[test]
+    fn tangent_root_is_not_a_sign_change() {
+        let square = |x: f64| x * x;
+        assert!(square(0.0).abs() < 1e-4);
+        assert!(!verify_root(square, 0.0, 1e-3));
+        assert!(verify_root(CUBIC.function, 478.0, 1e-3));
+    }
+
+    #[test]
+    fn cubic_has_three_brackets() {
+        let brackets = brackets(CUBIC.function, -5000.0..5000.0, 100);
+        assert_eq!(brackets.len(), 3);
+        for (bracket, root) in brackets.iter().zip(CUBIC.roots) {
+            assert!(
+                bracket.start <= *root && *root <= bracket.end,
+                "{bracket:?}"
+            );
+        }
+    }
+}
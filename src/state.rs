@@ -0,0 +1,153 @@
+use crate::{target::MaybeSync, Genome, Population, Result};
+use rand::Rng;
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use std::{
+    fs::File,
+    io::{BufReader, BufWriter, Write},
+    ops::Range,
+    path::Path,
+};
+
+/// Estado de uma população no meio de uma execução, no formato salvo por
+/// [`Population::save_state`].
+///
+/// A função, a estratégia e os hiperparâmetros não fazem parte do estado: eles vêm da
+/// população em que o estado é carregado.
+#[derive(Serialize, Deserialize)]
+pub(crate) struct State {
+    pub(crate) individuals: Vec<Genome>,
+    pub(crate) generation: u64,
+    pub(crate) range: Vec<Range<f64>>,
+    pub(crate) global_best: Option<Genome>,
+    pub(crate) best: Option<Genome>,
+    pub(crate) last_best: Option<Genome>,
+    pub(crate) best_fitness: Option<f64>,
+    pub(crate) initial_fitness: Option<f64>,
+    pub(crate) stagnation: u64,
+    pub(crate) plateau: u64,
+    pub(crate) unchanged: u64,
+    pub(crate) improving: u64,
+    pub(crate) mutation_boost: f64,
+    pub(crate) suppressed: Vec<Genome>,
+}
+
+/// Estado de uma população junto com o do gerador de números aleatórios, no formato salvo
+/// por [`Population::save_state_with_rng`].
+#[derive(Serialize, Deserialize)]
+struct Checkpoint<R> {
+    state: State,
+    rng: R,
+}
+
+impl<R: Rng + MaybeSync> Population<R> {
+    /// Salva em JSON os indivíduos, a geração, os intervalos de busca e o melhor indivíduo
+    /// encontrado, para que a execução possa ser retomada com [`Population::load_state`].
+    pub fn save_state(&self, path: impl AsRef<Path>) -> Result<()> {
+        let mut writer = BufWriter::new(File::create(path)?);
+        serde_json::to_writer(&mut writer, &self.state())?;
+        writer.flush()?;
+        Ok(())
+    }
+
+    /// Carrega um estado salvo por [`Population::save_state`], substituindo os indivíduos e o
+    /// progresso atuais. A próxima execução continua da geração salva.
+    ///
+    /// A população deve ter sido criada com a mesma função e os mesmos hiperparâmetros da
+    /// original. Os contadores de `plateau_length`, `min_improvement_generations` e
+    /// `adaptive_size` continuam na próxima execução, como se ela não tivesse parado.
+    pub fn load_state(&mut self, path: impl AsRef<Path>) -> Result<()> {
+        let reader = BufReader::new(File::open(path)?);
+        let state = serde_json::from_reader(reader)?;
+        self.restore(state)
+    }
+}
+
+impl<R: Rng + MaybeSync + Serialize + DeserializeOwned> Population<R> {
+    /// Como [`Population::save_state`], mas também salva o estado do gerador de números
+    /// aleatórios, que precisa ser serializável, como o [`Pcg64`](crate::Pcg64).
+    pub fn save_state_with_rng(&self, path: impl AsRef<Path>) -> Result<()> {
+        let checkpoint = Checkpoint {
+            state: self.state(),
+            rng: self.rng(),
+        };
+        let mut writer = BufWriter::new(File::create(path)?);
+        serde_json::to_writer(&mut writer, &checkpoint)?;
+        writer.flush()?;
+        Ok(())
+    }
+
+    /// Carrega um estado salvo por [`Population::save_state_with_rng`], incluindo o do
+    /// gerador. Com a mesma função e os mesmos hiperparâmetros, a execução retomada produz
+    /// exatamente o mesmo resultado que a execução sem interrupção.
+    pub fn load_state_with_rng(&mut self, path: impl AsRef<Path>) -> Result<()> {
+        let reader = BufReader::new(File::open(path)?);
+        let checkpoint: Checkpoint<R> = serde_json::from_reader(reader)?;
+        self.restore(checkpoint.state)?;
+        *self.rng_mut() = checkpoint.rng;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{functions, AdaptiveSize, Pcg64, PopulationBuilder, Rearrangement};
+    use rand::SeedableRng;
+
+    /// Geração em que a execução é interrompida.
+    const CHECKPOINT: u64 = 325;
+
+    #[test]
+    fn resumed_run_matches_uninterrupted_run() {
+        let configurations: [fn(PopulationBuilder<Pcg64>) -> PopulationBuilder<Pcg64>; 4] = [
+            |builder| builder,
+            |builder| builder.plateau_length(3),
+            |builder| builder.min_improvement_generations(200),
+            |builder| {
+                builder.adaptive_size(AdaptiveSize {
+                    min: 50,
+                    max: 150,
+                    step: 10,
+                })
+            },
+        ];
+
+        for (i, configure) in configurations.into_iter().enumerate() {
+            let builder = |max_generations| {
+                configure(
+                    PopulationBuilder::new(functions::CUBIC.function)
+                        .rng(Pcg64::seed_from_u64(7))
+                        .rearrangement(Rearrangement::Genocide)
+                        .max_generations(max_generations),
+                )
+            };
+            let path = std::env::temp_dir().join(format!(
+                "rootfn-state-{}-{}.json",
+                std::process::id(),
+                i
+            ));
+
+            let uninterrupted = builder(5_000).build().unwrap().run(false).unwrap();
+            assert!(uninterrupted.generations > CHECKPOINT, "configuration {i}");
+
+            let mut pop = builder(CHECKPOINT).build().unwrap();
+            pop.run(false).unwrap();
+            pop.save_state_with_rng(&path).unwrap();
+
+            let mut resumed = builder(5_000).build().unwrap();
+            resumed.load_state_with_rng(&path).unwrap();
+            let result = resumed.run(false).unwrap();
+            std::fs::remove_file(&path).unwrap();
+
+            assert_eq!(result.root, uninterrupted.root, "configuration {i}");
+            assert_eq!(result.fitness, uninterrupted.fitness, "configuration {i}");
+            assert_eq!(
+                result.generations, uninterrupted.generations,
+                "configuration {i}"
+            );
+            assert_eq!(
+                result.stalled_out, uninterrupted.stalled_out,
+                "configuration {i}"
+            );
+        }
+    }
+}
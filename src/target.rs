@@ -0,0 +1,124 @@
+use crate::Norm;
+#[cfg(feature = "complex")]
+use num_complex::Complex;
+
+/// Limites exigidos das funções avaliadas pelo algoritmo.
+///
+/// Com a *feature* `rayon` as avaliações são feitas em paralelo, então as funções precisam
+/// ser `Send + Sync`. Sem ela, qualquer função serve.
+#[cfg(feature = "rayon")]
+pub trait MaybeSync: Send + Sync {}
+#[cfg(feature = "rayon")]
+impl<T: Send + Sync> MaybeSync for T {}
+
+/// Limites exigidos das funções avaliadas pelo algoritmo.
+///
+/// Com a *feature* `rayon` as avaliações são feitas em paralelo, então as funções precisam
+/// ser `Send + Sync`. Sem ela, qualquer função serve.
+#[cfg(not(feature = "rayon"))]
+pub trait MaybeSync {}
+#[cfg(not(feature = "rayon"))]
+impl<T> MaybeSync for T {}
+
+/// Função de uma variável.
+#[cfg(feature = "rayon")]
+pub(crate) type ScalarFn = Box<dyn Fn(f64) -> f64 + Send + Sync>;
+#[cfg(not(feature = "rayon"))]
+pub(crate) type ScalarFn = Box<dyn Fn(f64) -> f64>;
+
+/// Função de uma variável que pode falhar, com `None` nos pontos em que não pode ser
+/// avaliada.
+#[cfg(feature = "rayon")]
+pub(crate) type FallibleFn = Box<dyn Fn(f64) -> Option<f64> + Send + Sync>;
+#[cfg(not(feature = "rayon"))]
+pub(crate) type FallibleFn = Box<dyn Fn(f64) -> Option<f64>>;
+
+/// Converte o valor da função na *fitness* de um indivíduo. Menor é melhor.
+#[cfg(feature = "rayon")]
+pub(crate) type FitnessFn = Box<dyn Fn(f64) -> f64 + Send + Sync>;
+#[cfg(not(feature = "rayon"))]
+pub(crate) type FitnessFn = Box<dyn Fn(f64) -> f64>;
+
+/// Penalidade somada à *fitness* de um indivíduo, para favorecer raízes com alguma
+/// propriedade.
+#[cfg(feature = "rayon")]
+pub(crate) type PenaltyFn = Box<dyn Fn(&[f64]) -> f64 + Send + Sync>;
+#[cfg(not(feature = "rayon"))]
+pub(crate) type PenaltyFn = Box<dyn Fn(&[f64]) -> f64>;
+
+/// Função de uma variável avaliada em vários pontos de uma vez.
+#[cfg(feature = "rayon")]
+pub(crate) type BatchFn = Box<dyn Fn(&[f64]) -> Vec<f64> + Send + Sync>;
+#[cfg(not(feature = "rayon"))]
+pub(crate) type BatchFn = Box<dyn Fn(&[f64]) -> Vec<f64>>;
+
+/// Função de várias variáveis que retorna um vetor de resíduos.
+#[cfg(feature = "rayon")]
+pub(crate) type SystemFn = Box<dyn Fn(&[f64]) -> Vec<f64> + Send + Sync>;
+#[cfg(not(feature = "rayon"))]
+pub(crate) type SystemFn = Box<dyn Fn(&[f64]) -> Vec<f64>>;
+
+/// Função de uma variável complexa.
+#[cfg(all(feature = "complex", feature = "rayon"))]
+pub(crate) type ComplexFn = Box<dyn Fn(Complex<f64>) -> Complex<f64> + Send + Sync>;
+#[cfg(all(feature = "complex", not(feature = "rayon")))]
+pub(crate) type ComplexFn = Box<dyn Fn(Complex<f64>) -> Complex<f64>>;
+
+/// Função cuja raiz está sendo buscada.
+pub(crate) enum Target {
+    /// Função de uma variável, `f: R -> R`.
+    Scalar(ScalarFn),
+    /// Função de uma variável que pode falhar.
+    Fallible(FallibleFn),
+    /// Função de uma variável que recebe vários pontos e retorna o valor em cada um, na
+    /// mesma ordem.
+    Batch(BatchFn),
+    /// Sistema de equações, `f: R^n -> R^m`.
+    System {
+        dimension: usize,
+        function: SystemFn,
+    },
+    /// Função de uma variável complexa, `f: C -> C`. Os indivíduos têm duas componentes,
+    /// a parte real e a imaginária.
+    #[cfg(feature = "complex")]
+    Complex(ComplexFn),
+}
+
+impl Target {
+    /// Dimensão dos indivíduos avaliados pela função.
+    pub fn dimension(&self) -> usize {
+        match self {
+            Target::Scalar(_) | Target::Fallible(_) | Target::Batch(_) => 1,
+            Target::System { dimension, .. } => *dimension,
+            #[cfg(feature = "complex")]
+            Target::Complex(_) => 2,
+        }
+    }
+
+    /// `true` para funções de uma variável real, cujo valor tem sinal.
+    pub fn is_scalar(&self) -> bool {
+        matches!(
+            self,
+            Target::Scalar(_) | Target::Fallible(_) | Target::Batch(_)
+        )
+    }
+
+    /// Avalia a função em `x`. Para sistemas, retorna a `norm` do resíduo, e para funções
+    /// complexas, a `norm` de `[re, im]`; com [`Norm::L2`], o módulo do valor.
+    ///
+    /// `None` quando uma função que pode falhar falha em `x`.
+    pub fn eval(&self, x: &[f64], norm: Norm) -> Option<f64> {
+        let value = match self {
+            Target::Scalar(f) => f(x[0]),
+            Target::Fallible(f) => return f(x[0]),
+            Target::Batch(f) => f(&x[..1]).first().copied().unwrap_or(f64::NAN),
+            Target::System { function, .. } => norm.apply(&function(x)),
+            #[cfg(feature = "complex")]
+            Target::Complex(f) => {
+                let value = f(Complex::new(x[0], x[1]));
+                norm.apply(&[value.re, value.im])
+            }
+        };
+        Some(value)
+    }
+}